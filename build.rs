@@ -0,0 +1,105 @@
+//! Generates one test per stored example and part with a known answer, see `src/examples.rs`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+const DEFAULT_INPUT_DIR: &str = "src/inputs";
+
+/// Parses `prefix` followed by a number and `suffix`, e.g. `day01.examples`.
+fn number(name: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    name.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+fn test_name(year: u32, day: u32, example: &str, part: u8) -> String {
+    let example: String = example
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    format!("y{year}_day{day:02}_{example}_part{part}")
+}
+
+/// Names and paths of the entries of a directory, sorted, or none if it can't be read.
+fn entries(dir: &Path) -> Vec<(String, PathBuf)> {
+    let mut entries: Vec<_> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().to_string(),
+                entry.path(),
+            )
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+fn generate(dir: &Path) -> String {
+    let mut tests = Vec::new();
+    for (name, year_dir) in entries(dir) {
+        let Some(year) = number(&name, "y", "") else {
+            continue;
+        };
+        for (name, examples_dir) in entries(&year_dir) {
+            let Some(day) = number(&name, "day", ".examples") else {
+                continue;
+            };
+            for (example, _) in entries(&examples_dir) {
+                if example.ends_with(".answers") || example.starts_with('.') {
+                    continue;
+                }
+                let answers = fs::read_to_string(examples_dir.join(format!("{example}.answers")))
+                    .unwrap_or_default();
+                for (part, _) in answers.lines().filter_map(|line| line.split_once(':')) {
+                    let Ok(part) = part.trim().parse::<u8>() else {
+                        continue;
+                    };
+                    tests.push(format!(
+                        "#[test]\nfn {}() {{\n    crate::examples::check(std::path::Path::new(DIR), {}, {}, {:?}, {});\n}}\n",
+                        test_name(year, day, &example, part),
+                        year,
+                        day,
+                        example,
+                        part
+                    ));
+                }
+            }
+        }
+    }
+
+    format!(
+        "const DIR: &str = {:?};\n\n{}",
+        dir.display(),
+        tests.join("\n")
+    )
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed={INPUT_DIR_VAR}");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let dir = Path::new(&manifest_dir)
+        .join(env::var(INPUT_DIR_VAR).unwrap_or(DEFAULT_INPUT_DIR.to_string()));
+    // Watching a missing directory would rerun the script on every build, so watch the
+    // closest existing parent to notice when it gets created.
+    if let Some(watched) = dir.ancestors().find(|path| path.exists()) {
+        println!("cargo:rerun-if-changed={}", watched.display());
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests.rs");
+    fs::write(out, generate(&dir)).unwrap();
+}
@@ -0,0 +1,198 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    runner::{Outcome, PartReport},
+    solution::Answer,
+};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KnownAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl KnownAnswers {
+    pub fn parse(input: &str) -> Self {
+        input.lines().filter_map(|line| line.split_once(':')).fold(
+            Self::default(),
+            |mut acc, (part, answer)| {
+                if let Ok(part) = part.trim().parse() {
+                    acc.set(part, answer.trim());
+                }
+                acc
+            },
+        )
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, part: u8, answer: &str) {
+        match part {
+            1 => self.part1 = Some(answer.to_string()),
+            2 => self.part2 = Some(answer.to_string()),
+            _ => (),
+        }
+    }
+}
+
+impl Display for KnownAnswers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for part in [1, 2] {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "{part}: {answer}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Answers live next to the input, e.g. `y2023/day08.answers` for `y2023/day08`.
+pub fn answers_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("y{}", year))
+        .join(format!("day{:02}.answers", day))
+}
+
+pub fn load(dir: &Path, year: u32, day: u32) -> KnownAnswers {
+    fs::read_to_string(answers_path(dir, year, day))
+        .map(|content| KnownAnswers::parse(&content))
+        .unwrap_or_default()
+}
+
+pub fn record(dir: &Path, year: u32, day: u32, part: u8, answer: &str) -> std::io::Result<()> {
+    let mut known = load(dir, year, day);
+    known.set(part, answer);
+    let path = answers_path(dir, year, day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, known.to_string())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Missing,
+    Skipped,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "FAIL",
+            Verdict::Missing => "missing",
+            Verdict::Skipped => "skipped",
+        };
+        f.pad(s)
+    }
+}
+
+pub fn verify(outcome: &Outcome, expected: Option<&str>) -> Verdict {
+    match (outcome, expected) {
+        (Outcome::MissingInput | Outcome::Solved(Answer::NotImplemented), _) => Verdict::Skipped,
+        (_, None) => Verdict::Missing,
+        (Outcome::Solved(answer), Some(expected)) if answer.to_string() == expected => {
+            Verdict::Pass
+        }
+        _ => Verdict::Fail,
+    }
+}
+
+/// Compares every report with the stored answers and prints the results, recording the
+/// answers of the parts that have none stored yet when `record_missing` is set.
+///
+/// Returns whether every verified part passed.
+pub fn verify_reports(reports: &[PartReport], dir: &Path, record_missing: bool) -> bool {
+    let mut all_passed = true;
+    let mut counts = [0; 4];
+
+    println!("Year  Day  Part  Result   Answer / Expected");
+    for report in reports {
+        let known = load(dir, report.year, report.day);
+        let expected = known.get(report.part);
+        let verdict = verify(&report.outcome, expected);
+
+        let detail = match &verdict {
+            Verdict::Fail => format!("{} / {}", report.outcome, expected.unwrap_or("-")),
+            _ => report.outcome.to_string(),
+        };
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<7}  {}",
+            report.year, report.day, report.part, verdict, detail
+        );
+
+        if verdict == Verdict::Missing && record_missing {
+            if let Outcome::Solved(answer) = &report.outcome {
+                if let Err(e) = record(
+                    dir,
+                    report.year,
+                    report.day,
+                    report.part,
+                    &answer.to_string(),
+                ) {
+                    eprintln!("Unable to record answer: {e}");
+                }
+            }
+        }
+
+        all_passed &= verdict != Verdict::Fail;
+        counts[verdict as usize] += 1;
+    }
+
+    println!(
+        "{} passed, {} failed, {} missing, {} skipped",
+        counts[0], counts[1], counts[2], counts[3]
+    );
+    all_passed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_known_answers() {
+        let known = KnownAnswers::parse("1: 14429\n2: 10921547990923\n");
+        assert_eq!(known.get(1), Some("14429"));
+        assert_eq!(known.get(2), Some("10921547990923"));
+        assert_eq!(known.get(3), None);
+
+        let known = KnownAnswers::parse("2: abc\n");
+        assert_eq!(known.get(1), None);
+        assert_eq!(known.get(2), Some("abc"));
+    }
+
+    #[test]
+    fn test_known_answers_roundtrip() {
+        let mut known = KnownAnswers::default();
+        known.set(2, "42");
+        assert_eq!(known.to_string(), "2: 42\n");
+        known.set(1, "7");
+        assert_eq!(KnownAnswers::parse(&known.to_string()), known);
+    }
+
+    #[test]
+    fn test_verify() {
+        let solved = Outcome::Solved(Answer::Number(42));
+        assert_eq!(verify(&solved, Some("42")), Verdict::Pass);
+        assert_eq!(verify(&solved, Some("41")), Verdict::Fail);
+        assert_eq!(verify(&solved, None), Verdict::Missing);
+        assert_eq!(verify(&Outcome::Panicked, Some("42")), Verdict::Fail);
+        assert_eq!(verify(&Outcome::MissingInput, Some("42")), Verdict::Skipped);
+        assert_eq!(
+            verify(&Outcome::Solved(Answer::NotImplemented), None),
+            Verdict::Skipped
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    runner::Day,
+    solution::Timings,
+    utils::io::{InputError, InputProvider},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+        })
+    }
+}
+
+pub struct Report {
+    pub parse: Option<Stats>,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Report {
+    fn stages(&self) -> [(&'static str, Option<Stats>); 3] {
+        [
+            ("parse", self.parse),
+            ("part1", Some(self.part1)),
+            ("part2", Some(self.part2)),
+        ]
+    }
+}
+
+pub fn run(day: &Day, input: &str, warmup: usize, iterations: usize) -> Report {
+    for _ in 0..warmup {
+        day.solution.timed_run(input, None);
+    }
+
+    let runs: Vec<Timings> = (0..iterations.max(1))
+        .map(|_| day.solution.timed_run(input, None))
+        .collect();
+
+    let parse: Vec<Duration> = runs.iter().filter_map(|t| t.parse).collect();
+    let part1: Vec<Duration> = runs
+        .iter()
+        .filter_map(|t| t.part1.as_ref())
+        .map(|p| p.time)
+        .collect();
+    let part2: Vec<Duration> = runs
+        .iter()
+        .filter_map(|t| t.part2.as_ref())
+        .map(|p| p.time)
+        .collect();
+
+    Report {
+        parse: Stats::from_samples(&parse),
+        part1: Stats::from_samples(&part1).expect("at least one iteration"),
+        part2: Stats::from_samples(&part2).expect("at least one iteration"),
+    }
+}
+
+/// Where baselines are kept: the target directory of the crate, so that they don't depend on
+/// where `aoc bench` is started from.
+pub fn baseline_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("aoc-bench")
+}
+
+/// Baselines are stored as one `stage median_in_nanoseconds` line per stage.
+pub fn baseline_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("y{}", year))
+        .join(format!("day{:02}", day))
+}
+
+pub fn save_baseline(dir: &Path, year: u32, day: u32, report: &Report) -> std::io::Result<()> {
+    let path = baseline_path(dir, year, day);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let content: String = report
+        .stages()
+        .iter()
+        .filter_map(|(name, stats)| stats.map(|s| format!("{name} {}\n", s.median.as_nanos())))
+        .collect();
+    fs::write(path, content)
+}
+
+pub fn load_baseline(dir: &Path, year: u32, day: u32) -> Vec<(String, Duration)> {
+    fs::read_to_string(baseline_path(dir, year, day))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let (name, nanos) = line.split_once(' ')?;
+            Some((name.to_string(), Duration::from_nanos(nanos.parse().ok()?)))
+        })
+        .collect()
+}
+
+/// The change in percent, `None` for a 0ns baseline, which coarse clocks give for the fastest
+/// parts.
+fn change(baseline: Duration, current: Duration) -> Option<f64> {
+    (!baseline.is_zero()).then(|| (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+pub fn print_report(baseline_file: &Path, report: &Report, baseline: &[(String, Duration)]) {
+    println!(
+        "Stage  {:>12}  {:>12}  {:>12}  {:>12}  Change",
+        "Min", "Median", "Mean", "Baseline"
+    );
+    for (name, stats) in report.stages() {
+        let Some(stats) = stats else {
+            println!("{name}  {:>12}", "-");
+            continue;
+        };
+        let previous = baseline.iter().find(|(n, _)| n == name).map(|(_, d)| *d);
+        let (previous, change) = match previous {
+            Some(previous) => (
+                format!("{:.2?}", previous),
+                change(previous, stats.median)
+                    .map_or("n/a".to_string(), |change| format!("{:+.1}%", change)),
+            ),
+            None => ("-".to_string(), String::new()),
+        };
+        let line = format!(
+            "{name}  {:>12}  {:>12}  {:>12}  {:>12}  {}",
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            previous,
+            change
+        );
+        println!("{}", line.trim_end());
+    }
+    if baseline.is_empty() {
+        println!("No baseline stored at {}", baseline_file.display());
+    }
+}
+
+pub fn bench(
+    year: u32,
+    day: &Day,
+    inputs: &InputProvider,
+    warmup: usize,
+    iterations: usize,
+    save: bool,
+) -> Result<(), InputError> {
+    let input = inputs.read(year, day.day)?;
+    println!(
+        "Benchmarking {} day {} ({} warm-up, {} iterations)",
+        year, day.day, warmup, iterations
+    );
+    let report = run(day, &input, warmup, iterations);
+    let dir = baseline_dir();
+    print_report(
+        &baseline_path(&dir, year, day.day),
+        &report,
+        &load_baseline(&dir, year, day.day),
+    );
+
+    if save {
+        match save_baseline(&dir, year, day.day, &report) {
+            Ok(()) => println!("Baseline saved"),
+            Err(e) => eprintln!("Unable to save baseline: {e}"),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2750));
+
+        let stats = Stats::from_samples(&[Duration::from_millis(4)]).unwrap();
+        assert_eq!(stats.median, Duration::from_millis(4));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let dir = std::env::temp_dir().join(format!("aoc_bench_{}", std::process::id()));
+        let (year, day) = (2023, 17);
+        let stats = |millis| Stats::from_samples(&[Duration::from_millis(millis)]).unwrap();
+        let report = Report {
+            parse: None,
+            part1: stats(3),
+            part2: stats(250),
+        };
+
+        assert!(load_baseline(&dir, year, day).is_empty());
+        save_baseline(&dir, year, day, &report).unwrap();
+        let baseline = load_baseline(&dir, year, day);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            baseline,
+            vec![
+                ("part1".to_string(), Duration::from_millis(3)),
+                ("part2".to_string(), Duration::from_millis(250)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = Duration::from_millis(200);
+        assert!((change(baseline, Duration::from_millis(100)).unwrap() + 50.0).abs() < 1e-9);
+        assert!((change(baseline, Duration::from_millis(300)).unwrap() - 50.0).abs() < 1e-9);
+        assert_eq!(change(Duration::ZERO, Duration::from_nanos(100)), None);
+    }
+}
@@ -0,0 +1,86 @@
+use std::{fs, path::PathBuf, time::Duration};
+
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+pub const DEFAULT_CONFIG: &str = "./aoc.toml";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Settings read from `aoc.toml` (or the file in `AOC_CONFIG`), one `key = "value"` per line:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// rate_limit_secs = 5
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub rate_limit: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            rate_limit: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        std::env::var_os(CONFIG_VAR)
+            .unwrap_or(DEFAULT_CONFIG.into())
+            .into()
+    }
+
+    pub fn parse(input: &str) -> Self {
+        input
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .filter_map(|line| line.split_once('='))
+            .fold(Self::default(), |mut acc, (key, value)| {
+                let value = value.trim().trim_matches('"');
+                match key.trim() {
+                    "session" if !value.is_empty() => acc.session = Some(value.to_string()),
+                    "base_url" => acc.base_url = value.trim_end_matches('/').to_string(),
+                    "rate_limit_secs" => {
+                        if let Ok(secs) = value.parse() {
+                            acc.rate_limit = Duration::from_secs(secs);
+                        }
+                    }
+                    _ => {}
+                }
+                acc
+            })
+    }
+
+    /// Loads the config file, falling back to the defaults when it does not exist.
+    pub fn load() -> Self {
+        fs::read_to_string(Self::path())
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "# Advent of Code
+session = \"abc123\"
+base_url = \"http://localhost:8080/\"
+rate_limit_secs = 1
+unknown = 3",
+        );
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+        assert_eq!(config.rate_limit, Duration::from_secs(1));
+
+        assert_eq!(Config::parse(""), Config::default());
+    }
+}
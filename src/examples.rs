@@ -0,0 +1,190 @@
+//! Puzzle examples stored as files next to the inputs:
+//!
+//! ```text
+//! y2023/day01.examples/example1          input of the example
+//! y2023/day01.examples/example1.answers  expected answers, as in `day01.answers`
+//! y2023/day01.examples/larger            a day can have as many examples as needed
+//! ```
+//!
+//! `build.rs` generates one test per example and part with a known answer.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{answers::KnownAnswers, runner, utils::io::normalize};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: KnownAnswers,
+}
+
+pub fn examples_dir(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("y{}", year))
+        .join(format!("day{:02}.examples", day))
+}
+
+/// Loads every example of a day sorted by name. A day without examples has none.
+pub fn load(dir: &Path, year: u32, day: u32) -> io::Result<Vec<Example>> {
+    let dir = examples_dir(dir, year, day);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut examples = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        if name.ends_with(".answers") || name.starts_with('.') {
+            continue;
+        }
+
+        let answers = fs::read_to_string(dir.join(format!("{name}.answers")))
+            .map(|content| KnownAnswers::parse(&content))
+            .unwrap_or_default();
+        examples.push(Example {
+            name,
+            input: normalize(&fs::read_to_string(&path)?),
+            answers,
+        });
+    }
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(examples)
+}
+
+/// Runs one part of a registered day on an example and panics if the answer differs from the
+/// stored one. Called by the tests generated from the examples.
+pub fn check(dir: &Path, year: u32, day: u32, name: &str, part: u8) {
+    let solution = runner::find(year, day)
+        .unwrap_or_else(|| panic!("no solution registered for {year} day {day}"))
+        .solution;
+    let example = load(dir, year, day)
+        .unwrap()
+        .into_iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("example {name} of {year} day {day} not found"));
+    let expected = example
+        .answers
+        .get(part)
+        .unwrap_or_else(|| panic!("example {name} has no answer for part {part}"));
+
+    let answer = match part {
+        1 => solution.part1(&example.input),
+        _ => solution.part2(&example.input),
+    };
+    assert_eq!(
+        answer.to_string(),
+        expected,
+        "{year} day {day} example {name} part {part}"
+    );
+}
+
+/// Pulls the `<pre><code>` blocks out of a puzzle page, which is where the examples live.
+pub fn extract(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        .skip(1)
+        .filter_map(|block| block.split_once("</code></pre>"))
+        .map(|(code, _)| unescape(&strip_tags(code)))
+        .collect()
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Stores the examples of a puzzle page as `example1`, `example2`... keeping existing files, and
+/// returns the paths written. The answers have to be added by hand.
+pub fn store(dir: &Path, year: u32, day: u32, html: &str) -> io::Result<Vec<PathBuf>> {
+    let dir = examples_dir(dir, year, day);
+    fs::create_dir_all(&dir)?;
+
+    let mut written = Vec::new();
+    for (i, example) in extract(html).iter().enumerate() {
+        let path = dir.join(format!("example{}", i + 1));
+        if !path.exists() {
+            fs::write(&path, example)?;
+            written.push(path);
+        }
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract() {
+        let html = "<article><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+<pre><code>a &lt;- <em>b</em> &amp;&amp; c
+</code></pre></article>";
+
+        assert_eq!(extract(html), vec!["1abc2\npqr3stu8vwx\n", "a <- b && c\n"]);
+        assert!(extract("<p>no examples</p>").is_empty());
+    }
+
+    #[test]
+    fn test_load_and_check() {
+        let dir = std::env::temp_dir().join(format!("aoc_examples_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        assert!(load(&dir, 2023, 1).unwrap().is_empty());
+
+        let html = "<pre><code>1abc2\r\npqr3stu8vwx\r\na1b2c3d4e5f\r\ntreb7uchet</code></pre>
+<pre><code>two1nine\neightwothree</code></pre>";
+        assert_eq!(store(&dir, 2023, 1, html).unwrap().len(), 2);
+        assert!(store(&dir, 2023, 1, html).unwrap().is_empty());
+
+        let examples = examples_dir(&dir, 2023, 1);
+        fs::write(examples.join("example1.answers"), "1: 142\n").unwrap();
+        fs::write(examples.join("example2.answers"), "2: 112\n").unwrap();
+
+        let loaded = load(&dir, 2023, 1).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].name, "example1");
+        assert_eq!(
+            loaded[0].input,
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n"
+        );
+        assert_eq!(loaded[1].answers.get(1), None);
+
+        check(&dir, 2023, 1, "example1", 1);
+        check(&dir, 2023, 1, "example2", 2);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
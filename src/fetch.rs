@@ -0,0 +1,265 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs, io,
+    path::PathBuf,
+    thread::sleep,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use ureq::Agent;
+
+use crate::{config::Config, examples, utils::io::InputProvider};
+
+pub const USER_AGENT: &str = "aoc-rust-solutions (input fetcher)";
+
+/// File in the input directory holding the time of the last request, in milliseconds since the
+/// epoch, so the rate limit holds across invocations.
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+#[derive(Debug)]
+pub enum FetchError {
+    MissingSession(PathBuf),
+    AlreadyCached(PathBuf),
+    Status(u16),
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingSession(config) => {
+                write!(
+                    f,
+                    "no session token, add `session = \"...\"` to {}",
+                    config.display()
+                )
+            }
+            FetchError::AlreadyCached(path) => {
+                write!(f, "input already downloaded at {}", path.display())
+            }
+            FetchError::Status(status) => write!(f, "server answered with status {status}"),
+            FetchError::Http(e) => write!(f, "request failed: {e}"),
+            FetchError::Io(e) => write!(f, "unable to store input: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<ureq::Error> for FetchError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::StatusCode(status) => FetchError::Status(status),
+            e => FetchError::Http(e),
+        }
+    }
+}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+/// Talks to the Advent of Code site, or anything serving the same routes under `base_url`.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    rate_limit: Duration,
+    dir: PathBuf,
+}
+
+impl Client {
+    /// `dir` is the input directory, used as cache and to remember when the last request was made.
+    pub fn new(config: &Config, dir: impl Into<PathBuf>) -> Result<Self, FetchError> {
+        let session = config
+            .session
+            .clone()
+            .ok_or_else(|| FetchError::MissingSession(Config::path()))?;
+        let agent = Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Ok(Self {
+            agent,
+            base_url: config.base_url.clone(),
+            session,
+            rate_limit: config.rate_limit,
+            dir: dir.into(),
+        })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        InputProvider::directory(&self.dir)
+            .path(year, day)
+            .expect("a directory provider always has a path")
+    }
+
+    /// Downloads the input of a day into the input directory and returns where it was stored.
+    /// An input that is already there is never downloaded again.
+    pub fn fetch(&self, year: u32, day: u32) -> Result<PathBuf, FetchError> {
+        let path = self.input_path(year, day);
+        if path.exists() {
+            return Err(FetchError::AlreadyCached(path));
+        }
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let input = self.get(&url)?.into_body().read_to_string()?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, input)?;
+        Ok(path)
+    }
+
+    /// Stores the examples of the puzzle page next to the input, see `examples::store`.
+    pub fn fetch_examples(&self, year: u32, day: u32) -> Result<Vec<PathBuf>, FetchError> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        let page = self.get(&url)?.into_body().read_to_string()?;
+        Ok(examples::store(&self.dir, year, day, &page)?)
+    }
+
+    pub fn get(&self, url: &str) -> Result<ureq::http::Response<ureq::Body>, FetchError> {
+        self.wait_rate_limit()?;
+        Ok(self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()?)
+    }
+
+    pub fn post(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+    ) -> Result<ureq::http::Response<ureq::Body>, FetchError> {
+        self.wait_rate_limit()?;
+        Ok(self
+            .agent
+            .post(url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form(form.iter().copied())?)
+    }
+
+    /// Sleeps until `rate_limit` has passed since the previous request and records this one.
+    fn wait_rate_limit(&self) -> io::Result<()> {
+        let stamp = self.dir.join(LAST_REQUEST_FILE);
+        let last = fs::read_to_string(&stamp)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(last) = last {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if elapsed < self.rate_limit {
+                sleep(self.rate_limit - elapsed);
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        fs::create_dir_all(&self.dir)?;
+        fs::write(stamp, now.as_millis().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::stand_in;
+
+    fn client(base_url: String, name: &str, rate_limit: Duration) -> Client {
+        let config = Config {
+            session: Some("token".to_string()),
+            base_url,
+            rate_limit,
+        };
+        let dir = std::env::temp_dir().join(format!("aoc_fetch_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Client::new(&config, dir).unwrap()
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base_url, requests) = stand_in::serve(vec![(200, "1 2\n3 4\n")]);
+        let client = client(base_url, "fetch", Duration::ZERO);
+
+        let path = client.fetch(2023, 5).unwrap();
+        assert!(path.ends_with("y2023/day05"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.path, "/2023/day/5/input");
+        assert_eq!(request.header("cookie"), Some("session=token"));
+        assert_eq!(request.header("user-agent"), Some(USER_AGENT));
+
+        // The server only answers once, a second download would fail with an HTTP error.
+        assert!(matches!(
+            client.fetch(2023, 5),
+            Err(FetchError::AlreadyCached(_))
+        ));
+
+        fs::remove_dir_all(client.dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (base_url, _requests) = stand_in::serve(vec![(400, "Please log in")]);
+        let client = client(base_url, "errors", Duration::ZERO);
+
+        assert!(matches!(
+            client.fetch(2023, 5),
+            Err(FetchError::Status(400))
+        ));
+        assert!(!client.input_path(2023, 5).exists());
+
+        let config = Config::default();
+        assert!(matches!(
+            Client::new(&config, "."),
+            Err(FetchError::MissingSession(_))
+        ));
+
+        fs::remove_dir_all(client.dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_examples() {
+        let page = "<article><pre><code>1\n2\n</code></pre></article>";
+        let (base_url, requests) = stand_in::serve(vec![(200, page)]);
+        let client = client(base_url, "examples", Duration::ZERO);
+
+        let paths = client.fetch_examples(2023, 5).unwrap();
+        assert_eq!(requests.recv().unwrap().path, "/2023/day/5");
+        assert_eq!(paths.len(), 1);
+        assert!(paths[0].ends_with("y2023/day05.examples/example1"));
+        assert_eq!(fs::read_to_string(&paths[0]).unwrap(), "1\n2\n");
+
+        fs::remove_dir_all(client.dir).unwrap();
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let (base_url, _requests) = stand_in::serve(vec![(200, "1\n"), (200, "2\n")]);
+        let client = client(base_url, "rate_limit", Duration::from_millis(300));
+
+        let start = Instant::now();
+        client.fetch(2023, 1).unwrap();
+        client.fetch(2023, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+
+        fs::remove_dir_all(client.dir).unwrap();
+    }
+}
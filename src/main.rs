@@ -1,9 +1,263 @@
 #![allow(dead_code)]
+mod answers;
+mod bench;
+mod config;
+mod examples;
+mod fetch;
+mod runner;
+mod scaffold;
+mod solution;
+#[cfg(test)]
+mod stand_in;
+mod submit;
 mod utils;
-//mod y2015;
+mod y2015;
+mod y2023;
+mod y2024;
 mod y2025;
 
+use std::{path::PathBuf, time::Instant};
+
+use clap::{Parser, Subcommand};
+
+use solution::Answer;
+use utils::io::{InputProvider, DEFAULT_INPUT_DIR, INPUT_DIR_VAR};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    /// Directory holding the puzzle inputs, laid out as `y<year>/day<dd>`
+    #[arg(long, global = true, env = INPUT_DIR_VAR, default_value = DEFAULT_INPUT_DIR)]
+    input_dir: PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution of a single day
+    Run {
+        year: u32,
+        day: u32,
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from stdin instead of the input directory
+        #[arg(long)]
+        stdin: bool,
+    },
+    /// Run every registered day of a year, or of all years, and print a results table
+    All { year: Option<u32> },
+    /// Compare the answers of the selected days with the stored known-good answers
+    Verify {
+        year: Option<u32>,
+        day: Option<u32>,
+        /// Store the current answer of every part that has no known answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Time parsing and each part of a day over several iterations
+    Bench {
+        year: u32,
+        day: u32,
+        /// Untimed runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Timed runs
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+        /// Store the medians of this run as the baseline for later comparisons
+        #[arg(long)]
+        save_baseline: bool,
+        /// Read the input from stdin instead of the input directory
+        #[arg(long)]
+        stdin: bool,
+    },
+    /// Download the input of a day into the input directory, using the session in aoc.toml
+    Fetch {
+        year: u32,
+        day: u32,
+        /// Also store the examples of the puzzle page next to the input
+        #[arg(long)]
+        examples: bool,
+    },
+    /// Submit the answer of a part, computing it from the input when not given
+    Submit {
+        year: u32,
+        day: u32,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Create a day from src/template.rs and register it, creating the year module if needed
+    New {
+        year: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+}
+
+fn provider(input_dir: PathBuf, stdin: bool) -> InputProvider {
+    if stdin {
+        InputProvider::Stdin
+    } else {
+        InputProvider::directory(input_dir)
+    }
+}
+
 fn main() {
-    //y2015::run();
-    y2025::run();
+    let cli = Cli::parse();
+    let inputs = InputProvider::directory(&cli.input_dir);
+
+    match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            stdin,
+        } => match runner::find(year, day) {
+            Some(solution) => {
+                if let Err(e) = runner::run(year, solution, part, &provider(cli.input_dir, stdin)) {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+            None => {
+                eprintln!("No solution registered for year {year} day {day}");
+                std::process::exit(1);
+            }
+        },
+        Command::All { year } => {
+            let days = runner::days_of(year);
+            if days.is_empty() {
+                match year {
+                    Some(year) => eprintln!("No solutions registered for year {year}"),
+                    None => eprintln!("No solutions registered"),
+                }
+                std::process::exit(1);
+            }
+            let start_time = Instant::now();
+            let reports = runner::run_days(&days, &inputs);
+            runner::print_table(&reports, start_time.elapsed());
+        }
+        Command::Verify { year, day, record } => {
+            let days: Vec<_> = runner::days_of(year)
+                .into_iter()
+                .filter(|(_, d)| day.is_none_or(|day| d.day == day))
+                .collect();
+            if days.is_empty() {
+                eprintln!("No solutions registered for the selected days");
+                std::process::exit(1);
+            }
+            let reports = runner::run_days(&days, &inputs);
+            if !answers::verify_reports(&reports, &cli.input_dir, record) {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            year,
+            day,
+            warmup,
+            iterations,
+            save_baseline,
+            stdin,
+        } => match runner::find(year, day) {
+            Some(solution) => {
+                let inputs = provider(cli.input_dir, stdin);
+                if let Err(e) =
+                    bench::bench(year, solution, &inputs, warmup, iterations, save_baseline)
+                {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+            None => {
+                eprintln!("No solution registered for year {year} day {day}");
+                std::process::exit(1);
+            }
+        },
+        Command::Fetch {
+            year,
+            day,
+            examples,
+        } => {
+            let client = match fetch::Client::new(&config::Config::load(), &cli.input_dir) {
+                Ok(client) => client,
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            };
+            match client.fetch(year, day) {
+                Ok(path) => println!("Input of {year} day {day} stored at {}", path.display()),
+                // The examples can still be fetched for an input downloaded earlier.
+                Err(e @ fetch::FetchError::AlreadyCached(_)) if examples => eprintln!("{e}"),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+            if examples {
+                match client.fetch_examples(year, day) {
+                    Ok(paths) => {
+                        for path in paths {
+                            let path = path.display();
+                            println!("Example stored at {path}, add its answers to {path}.answers");
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("{e}");
+                        std::process::exit(1);
+                    }
+                }
+            }
+        }
+        Command::New { year, day } => match scaffold::new_day(&scaffold::source_dir(), year, day) {
+            Ok(path) => println!("Created {}", path.display()),
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        },
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => {
+            let Some(solution) = runner::find(year, day) else {
+                eprintln!("No solution registered for year {year} day {day}");
+                std::process::exit(1);
+            };
+            let answer = answer.unwrap_or_else(|| {
+                let input = inputs.read(year, day).unwrap_or_else(|e| {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                });
+                match part {
+                    1 => solution.solution.part1(&input),
+                    _ => solution.solution.part2(&input),
+                }
+                .to_string()
+            });
+            if answer == Answer::NotImplemented.to_string() {
+                eprintln!("Part {part} of {year} day {day} is not implemented");
+                std::process::exit(1);
+            }
+            println!("Submitting {answer} for {year} day {day} part {part}");
+
+            let submitted = fetch::Client::new(&config::Config::load(), &cli.input_dir)
+                .map_err(submit::SubmitError::from)
+                .and_then(|client| {
+                    submit::submit(&client, &cli.input_dir, year, day, part, &answer)
+                });
+            match submitted {
+                Ok(response) => println!("{response}"),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
 }
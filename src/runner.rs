@@ -0,0 +1,217 @@
+use std::{
+    fmt::{Display, Formatter},
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+
+use crate::{
+    solution::{Answer, Solution},
+    utils::io::{InputError, InputProvider},
+    y2015, y2023, y2024, y2025,
+};
+
+pub struct Day {
+    pub day: u32,
+    pub solution: &'static dyn Solution,
+}
+
+impl Day {
+    pub const fn new(day: u32, solution: &'static dyn Solution) -> Self {
+        Self { day, solution }
+    }
+}
+
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub const fn new(year: u32, days: &'static [Day]) -> Self {
+        Self { year, days }
+    }
+}
+
+pub const YEARS: &[Year] = &[
+    Year::new(2015, y2015::DAYS),
+    Year::new(2023, y2023::DAYS),
+    Year::new(2024, y2024::DAYS),
+    Year::new(2025, y2025::DAYS),
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static Day> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)?
+        .days
+        .iter()
+        .find(|d| d.day == day)
+}
+
+pub fn run(
+    year: u32,
+    day: &Day,
+    part: Option<u8>,
+    inputs: &InputProvider,
+) -> Result<(), InputError> {
+    let input = inputs.read(year, day.day)?;
+
+    let timings = day.solution.timed_run(&input, part);
+    if let Some(parse) = timings.parse {
+        println!("Parsed input");
+        println!("\t time:{:?}", parse);
+    }
+
+    if let Some(part1) = timings.part1 {
+        println!("First star: {}", part1.answer);
+        println!("\t time:{:?}", part1.time);
+    }
+
+    if let Some(part2) = timings.part2 {
+        println!("Second star: {}", part2.answer);
+        println!("\t time:{:?}", part2.time);
+    }
+
+    Ok(())
+}
+
+pub enum Outcome {
+    Solved(Answer),
+    MissingInput,
+    Panicked,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::MissingInput => write!(f, "missing input"),
+            Outcome::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+pub struct PartReport {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub time: Duration,
+}
+
+fn solve_part(solution: &dyn Solution, part: u8, input: &str) -> (Outcome, Duration) {
+    let start_time = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| match part {
+        1 => solution.part1(input),
+        _ => solution.part2(input),
+    }));
+    let time = start_time.elapsed();
+
+    match result {
+        Ok(answer) => (Outcome::Solved(answer), time),
+        Err(_) => (Outcome::Panicked, time),
+    }
+}
+
+pub fn days_of(year: Option<u32>) -> Vec<(u32, &'static Day)> {
+    YEARS
+        .iter()
+        .filter(|y| year.is_none_or(|year| y.year == year))
+        .flat_map(|y| y.days.iter().map(move |day| (y.year, day)))
+        .collect()
+}
+
+pub fn run_days(days: &[(u32, &'static Day)], inputs: &InputProvider) -> Vec<PartReport> {
+    days.par_iter()
+        .flat_map_iter(|&(year, day)| {
+            let input = inputs.read(year, day.day).ok();
+            [1, 2].into_iter().map(move |part| {
+                let (outcome, time) = match &input {
+                    Some(input) => solve_part(day.solution, part, input),
+                    None => (Outcome::MissingInput, Duration::ZERO),
+                };
+                PartReport {
+                    year,
+                    day: day.day,
+                    part,
+                    outcome,
+                    time,
+                }
+            })
+        })
+        .collect()
+}
+
+pub fn print_table(reports: &[PartReport], wall_time: Duration) {
+    let answers: Vec<String> = reports.iter().map(|r| r.outcome.to_string()).collect();
+    let width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max(6);
+
+    println!("Year  Day  Part  {:<width$}  Time", "Answer");
+    for (report, answer) in reports.iter().zip(&answers) {
+        let time = match report.outcome {
+            Outcome::MissingInput => "-".to_string(),
+            _ => format!("{:.2?}", report.time),
+        };
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<width$}  {}",
+            report.year, report.day, report.part, answer, time
+        );
+    }
+
+    let total: Duration = reports.iter().map(|r| r.time).sum();
+    let solved = reports
+        .iter()
+        .filter(|r| {
+            matches!(
+                r.outcome,
+                Outcome::Solved(Answer::Number(_) | Answer::Text(_))
+            )
+        })
+        .count();
+    println!(
+        "Total: {:.2?} ({} of {} parts solved, wall time {:.2?})",
+        total,
+        solved,
+        reports.len(),
+        wall_time
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_are_unique() {
+        for year in YEARS {
+            let mut days: Vec<u32> = year.days.iter().map(|d| d.day).collect();
+            days.sort();
+            days.dedup();
+            assert_eq!(
+                days.len(),
+                year.days.len(),
+                "duplicated day in {}",
+                year.year
+            );
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(2023, 17).map(|d| d.day), Some(17));
+        assert!(find(2023, 26).is_none());
+        assert!(find(1999, 1).is_none());
+    }
+
+    #[test]
+    fn test_days_of() {
+        assert!(days_of(Some(2023)).iter().all(|(year, _)| *year == 2023));
+        assert_eq!(
+            days_of(None).len(),
+            YEARS.iter().map(|y| y.days.len()).sum::<usize>()
+        );
+        assert!(days_of(Some(1999)).is_empty());
+    }
+}
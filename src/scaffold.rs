@@ -0,0 +1,302 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    /// A file that should be edited does not look like the code this command generates.
+    UnexpectedLayout(PathBuf),
+    Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::UnexpectedLayout(path) => {
+                write!(
+                    f,
+                    "unable to find where to register the day in {}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(e: io::Error) -> Self {
+        ScaffoldError::Io(e)
+    }
+}
+
+pub fn render(year: u32, day: u32) -> String {
+    TEMPLATE
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{day:02}}", &format!("{day:02}"))
+}
+
+/// Inserts `line` among the lines for which `key` returns a number, keeping them sorted.
+/// Returns `None` if there is no such line to use as anchor.
+fn insert_sorted(
+    content: &str,
+    key: impl Fn(&str) -> Option<u32>,
+    new_key: u32,
+    line: &str,
+) -> Option<String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let keyed: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l).map(|k| (i, k)))
+        .collect();
+
+    let position = match keyed.iter().find(|(_, k)| *k > new_key) {
+        Some((i, _)) => *i,
+        None => keyed.last()?.0 + 1,
+    };
+    lines.insert(position, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn number_key(pattern: &str) -> impl Fn(&str) -> Option<u32> {
+    let regex = Regex::new(pattern).unwrap();
+    move |line| regex.captures(line)?[1].parse().ok()
+}
+
+/// The new content of `path`, without writing it yet.
+fn edited(
+    path: &Path,
+    f: impl Fn(&str) -> Option<String>,
+) -> Result<(PathBuf, String), ScaffoldError> {
+    let content = fs::read_to_string(path)?;
+    let edited = f(&content).ok_or_else(|| ScaffoldError::UnexpectedLayout(path.to_path_buf()))?;
+    Ok((path.to_path_buf(), edited))
+}
+
+/// Adds `mod dayNN;` and its `Day::new` entry to an existing year module.
+fn register_day(content: &str, day: u32) -> Option<String> {
+    let content = insert_sorted(
+        content,
+        number_key(r"^mod day(\d+);"),
+        day,
+        &format!("mod day{:02};", day),
+    )?;
+    insert_sorted(
+        &content,
+        number_key(r"^\s+Day::new\((\d+),"),
+        day,
+        &format!("    Day::new({}, &day{:02}::Solver),", day, day),
+    )
+}
+
+fn year_module(day: u32) -> String {
+    format!(
+        "use crate::runner::Day;\n\nmod day{:02};\n\npub const DAYS: &[Day] = &[\n    Day::new({}, &day{:02}::Solver),\n];\n",
+        day, day, day
+    )
+}
+
+/// Adds `mod yYYYY;` to main.rs.
+fn register_year_module(content: &str, year: u32) -> Option<String> {
+    insert_sorted(
+        content,
+        number_key(r"^mod y(\d{4});"),
+        year,
+        &format!("mod y{};", year),
+    )
+}
+
+/// Imports the year module in runner.rs and adds it to `YEARS`.
+fn register_year(content: &str, year: u32) -> Option<String> {
+    let import = Regex::new(r"(?m)^(\s+)((?:y\d{4}, )*y\d{4}),$").unwrap();
+    let captures = import.captures(content)?;
+    let mut years: Vec<&str> = captures[2].split(", ").collect();
+    let name = format!("y{}", year);
+    years.push(&name);
+    years.sort();
+    let content = import.replace(content, format!("{}{},", &captures[1], years.join(", ")));
+
+    insert_sorted(
+        &content,
+        number_key(r"^\s+Year::new\((\d+),"),
+        year,
+        &format!("    Year::new({}, y{}::DAYS),", year, year),
+    )
+}
+
+/// The source directory of the crate, wherever `aoc new` is started from.
+pub fn source_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// Creates `src/yYYYY/dayDD.rs` from the template and registers it, creating and registering
+/// the year module too when this is the first day of the year. Returns the new file.
+pub fn new_day(src: &Path, year: u32, day: u32) -> Result<PathBuf, ScaffoldError> {
+    let year_dir = src.join(format!("y{}", year));
+    let day_file = year_dir.join(format!("day{:02}.rs", day));
+    if day_file.exists() {
+        return Err(ScaffoldError::AlreadyExists(day_file));
+    }
+
+    // Every edit is prepared before writing anything, so that a file this command doesn't
+    // recognise leaves the tree untouched.
+    let year_file = src.join(format!("y{}.rs", year));
+    let registrations = if year_file.exists() {
+        vec![edited(&year_file, |content| register_day(content, day))?]
+    } else {
+        vec![
+            edited(&src.join("main.rs"), |content| {
+                register_year_module(content, year)
+            })?,
+            edited(&src.join("runner.rs"), |content| {
+                register_year(content, year)
+            })?,
+            (year_file, year_module(day)),
+        ]
+    };
+
+    // The day goes first, registering a module that doesn't exist would break the build.
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&day_file, render(year, day))?;
+    for (path, content) in registrations {
+        fs::write(path, content)?;
+    }
+    Ok(day_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let rendered = render(2016, 3);
+        assert!(rendered.starts_with("// https://adventofcode.com/2016/day/3\n"));
+        assert!(rendered.contains("`y2016/day03.examples/`"));
+        assert!(!rendered.contains("{{"));
+    }
+
+    #[test]
+    fn test_register_day() {
+        let content = "use crate::runner::Day;
+
+mod day01;
+mod day12;
+
+pub const DAYS: &[Day] = &[
+    Day::new(1, &day01::Solver),
+    Day::new(12, &day12::Solver),
+];
+";
+        let expected = "use crate::runner::Day;
+
+mod day01;
+mod day05;
+mod day12;
+
+pub const DAYS: &[Day] = &[
+    Day::new(1, &day01::Solver),
+    Day::new(5, &day05::Solver),
+    Day::new(12, &day12::Solver),
+];
+";
+        assert_eq!(register_day(content, 5).as_deref(), Some(expected));
+        assert!(register_day(&year_module(3), 20).unwrap().ends_with(
+            "mod day20;
+
+pub const DAYS: &[Day] = &[
+    Day::new(3, &day03::Solver),
+    Day::new(20, &day20::Solver),
+];
+"
+        ));
+        assert_eq!(register_day("fn main() {}", 5), None);
+    }
+
+    #[test]
+    fn test_register_year() {
+        let content = "use crate::{
+    solution::{Answer, Solution},
+    y2015, y2023,
+};
+
+pub const YEARS: &[Year] = &[
+    Year::new(2015, y2015::DAYS),
+    Year::new(2023, y2023::DAYS),
+];
+";
+        let expected = "use crate::{
+    solution::{Answer, Solution},
+    y2015, y2016, y2023,
+};
+
+pub const YEARS: &[Year] = &[
+    Year::new(2015, y2015::DAYS),
+    Year::new(2016, y2016::DAYS),
+    Year::new(2023, y2023::DAYS),
+];
+";
+        assert_eq!(register_year(content, 2016).as_deref(), Some(expected));
+        assert_eq!(
+            register_year_module("mod utils;\nmod y2015;\nmod y2023;\n", 2024).as_deref(),
+            Some("mod utils;\nmod y2015;\nmod y2023;\nmod y2024;\n")
+        );
+    }
+
+    #[test]
+    fn test_new_day() {
+        let src = std::env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("main.rs"), "mod runner;\nmod y2015;\n").unwrap();
+        fs::write(
+            src.join("runner.rs"),
+            "use crate::{\n    y2015,\n};\n\npub const YEARS: &[Year] = &[\n    Year::new(2015, y2015::DAYS),\n];\n",
+        )
+        .unwrap();
+
+        let file = new_day(&src, 2016, 1).unwrap();
+        assert_eq!(file, src.join("y2016").join("day01.rs"));
+        assert_eq!(fs::read_to_string(&file).unwrap(), render(2016, 1));
+        assert_eq!(
+            fs::read_to_string(src.join("y2016.rs")).unwrap(),
+            year_module(1)
+        );
+        assert!(fs::read_to_string(src.join("main.rs"))
+            .unwrap()
+            .ends_with("mod y2016;\n"));
+
+        new_day(&src, 2016, 2).unwrap();
+        assert!(fs::read_to_string(src.join("y2016.rs"))
+            .unwrap()
+            .contains("mod day01;\nmod day02;\n"));
+        assert!(matches!(
+            new_day(&src, 2016, 1),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+
+        fs::write(src.join("runner.rs"), "fn main() {}\n").unwrap();
+        let main = fs::read_to_string(src.join("main.rs")).unwrap();
+        assert!(matches!(
+            new_day(&src, 2017, 1),
+            Err(ScaffoldError::UnexpectedLayout(_))
+        ));
+        assert_eq!(fs::read_to_string(src.join("main.rs")).unwrap(), main);
+        assert!(!src.join("y2017").exists());
+        assert!(!src.join("y2017.rs").exists());
+
+        fs::remove_dir_all(src).unwrap();
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    fmt::{Display, Formatter},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    NotImplemented,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value)
+            .map(Answer::Number)
+            .unwrap_or_else(|_| Answer::Text(value.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+pub struct TimedAnswer {
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+/// The answers of a run with their timings, `None` for the parts that weren't asked for.
+pub struct Timings {
+    /// `None` when the day parses its input inside each part.
+    pub parse: Option<Duration>,
+    pub part1: Option<TimedAnswer>,
+    pub part2: Option<TimedAnswer>,
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start_time = Instant::now();
+    let result = black_box(f());
+    (result, start_time.elapsed())
+}
+
+/// Runs `solve` if `part` is asked for, all parts being asked for when `wanted` is `None`.
+fn timed_part(part: u8, wanted: Option<u8>, solve: impl FnOnce() -> Answer) -> Option<TimedAnswer> {
+    wanted.is_none_or(|wanted| wanted == part).then(|| {
+        let (answer, time) = timed(solve);
+        TimedAnswer { answer, time }
+    })
+}
+
+pub trait Solution: Sync {
+    fn part1(&self, input: &str) -> Answer;
+
+    fn part2(&self, _input: &str) -> Answer {
+        Answer::NotImplemented
+    }
+
+    /// Solves `part`, or both parts when it is `None`.
+    fn timed_run(&self, input: &str, part: Option<u8>) -> Timings {
+        Timings {
+            parse: None,
+            part1: timed_part(1, part, || self.part1(input)),
+            part2: timed_part(2, part, || self.part2(input)),
+        }
+    }
+}
+
+/// A day whose parts share a parsed representation of the input, so that parsing can be
+/// timed on its own.
+pub trait ParsedSolution: Sync {
+    type Parsed<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Self::Parsed<'a>;
+
+    fn part1(&self, parsed: &Self::Parsed<'_>) -> Answer;
+
+    fn part2(&self, _parsed: &Self::Parsed<'_>) -> Answer {
+        Answer::NotImplemented
+    }
+}
+
+impl<T: ParsedSolution> Solution for T {
+    fn part1(&self, input: &str) -> Answer {
+        ParsedSolution::part1(self, &self.parse(input))
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        ParsedSolution::part2(self, &self.parse(input))
+    }
+
+    fn timed_run(&self, input: &str, part: Option<u8>) -> Timings {
+        let (parsed, parse) = timed(|| self.parse(input));
+        Timings {
+            parse: Some(parse),
+            part1: timed_part(1, part, || ParsedSolution::part1(self, &parsed)),
+            part2: timed_part(2, part, || ParsedSolution::part2(self, &parsed)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42u32).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::NotImplemented.to_string(), "not implemented");
+    }
+
+    #[test]
+    fn test_answer_from_u128() {
+        assert_eq!(Answer::from(5u128), Answer::Number(5));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+}
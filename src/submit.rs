@@ -0,0 +1,352 @@
+use std::{
+    fmt::{Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answers,
+    fetch::{Client, FetchError},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answered too recently, with the time left when the page says it.
+    Wait(Option<Duration>),
+    /// The part is already solved or locked.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Response {
+    pub fn parse(html: &str) -> Self {
+        if html.contains("That's the right answer") {
+            Response::Correct
+        } else if html.contains("You gave an answer too recently") {
+            Response::Wait(parse_wait(html))
+        } else if html.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else if html.contains("your answer is too high") {
+            Response::TooHigh
+        } else if html.contains("your answer is too low") {
+            Response::TooLow
+        } else if html.contains("That's not the right answer") {
+            Response::Wrong
+        } else {
+            Response::Unknown(html.to_string())
+        }
+    }
+}
+
+/// Reads "You have 1m 23s left to wait" or "You have 45s left to wait".
+fn parse_wait(html: &str) -> Option<Duration> {
+    let (_, rest) = html.split_once("You have ")?;
+    let (time, _) = rest.split_once(" left to wait")?;
+    time.split_whitespace()
+        .map(|part| match part.split_at(part.len().checked_sub(1)?) {
+            (minutes, "m") => minutes.parse::<u64>().ok().map(|m| m * 60),
+            (seconds, "s") => seconds.parse::<u64>().ok(),
+            _ => None,
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Response::Correct => write!(f, "That's the right answer!"),
+            Response::TooHigh => write!(f, "Wrong, the answer is too high"),
+            Response::TooLow => write!(f, "Wrong, the answer is too low"),
+            Response::Wrong => write!(f, "Wrong answer"),
+            Response::Wait(Some(time)) => write!(f, "Answered too recently, wait {time:?}"),
+            Response::Wait(None) => write!(f, "Answered too recently, wait a bit"),
+            Response::WrongLevel => write!(f, "This part is already solved or still locked"),
+            Response::Unknown(_) => write!(f, "Unable to understand the response"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mistake {
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Mistake {
+    fn name(self) -> &'static str {
+        match self {
+            Mistake::TooHigh => "high",
+            Mistake::TooLow => "low",
+            Mistake::Wrong => "wrong",
+        }
+    }
+}
+
+/// Wrong answers already submitted for a day, stored next to the input as `dayDD.guesses`
+/// with one `<part> <high|low|wrong> <answer>` line per guess.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Guesses {
+    guesses: Vec<(u8, Mistake, String)>,
+}
+
+impl Guesses {
+    pub fn parse(input: &str) -> Self {
+        let guesses = input
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, ' ');
+                let part = fields.next()?.parse().ok()?;
+                let mistake = match fields.next()? {
+                    "high" => Mistake::TooHigh,
+                    "low" => Mistake::TooLow,
+                    "wrong" => Mistake::Wrong,
+                    _ => return None,
+                };
+                Some((part, mistake, fields.next()?.to_string()))
+            })
+            .collect();
+        Self { guesses }
+    }
+
+    pub fn add(&mut self, part: u8, mistake: Mistake, answer: &str) {
+        self.guesses.push((part, mistake, answer.to_string()));
+    }
+
+    /// Explains why `answer` can't be right for `part`, if the history already tells.
+    pub fn refuse(&self, part: u8, answer: &str) -> Option<String> {
+        let guesses = self.guesses.iter().filter(|(p, _, _)| *p == part);
+        for (_, mistake, guess) in guesses {
+            if guess == answer {
+                return Some(format!("{answer} was already submitted and is wrong"));
+            }
+
+            let (Ok(guess), Ok(answer)) = (guess.parse::<i128>(), answer.parse::<i128>()) else {
+                continue;
+            };
+            match mistake {
+                Mistake::TooHigh if answer >= guess => {
+                    return Some(format!("{answer} is too high, {guess} already was"))
+                }
+                Mistake::TooLow if answer <= guess => {
+                    return Some(format!("{answer} is too low, {guess} already was"))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (part, mistake, answer) in &self.guesses {
+            writeln!(f, "{} {} {}", part, mistake.name(), answer)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn guesses_path(dir: &Path, year: u32, day: u32) -> PathBuf {
+    dir.join(format!("y{}", year))
+        .join(format!("day{:02}.guesses", day))
+}
+
+pub fn load(dir: &Path, year: u32, day: u32) -> Guesses {
+    fs::read_to_string(guesses_path(dir, year, day))
+        .map(|content| Guesses::parse(&content))
+        .unwrap_or_default()
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(String),
+    Fetch(FetchError),
+    Io(io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitError::Refused(reason) => write!(f, "not submitted: {reason}"),
+            SubmitError::Fetch(e) => write!(f, "{e}"),
+            SubmitError::Io(e) => write!(f, "unable to store the guess: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<FetchError> for SubmitError {
+    fn from(e: FetchError) -> Self {
+        SubmitError::Fetch(e)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::Io(e)
+    }
+}
+
+/// Posts an answer unless the guess history in `dir` already rules it out, then records wrong
+/// answers in the history and correct ones as known answers for `verify`.
+pub fn submit(
+    client: &Client,
+    dir: &Path,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Response, SubmitError> {
+    let mut guesses = load(dir, year, day);
+    if let Some(reason) = guesses.refuse(part, answer) {
+        return Err(SubmitError::Refused(reason));
+    }
+
+    let url = format!("{}/{}/day/{}/answer", client.base_url(), year, day);
+    let level = part.to_string();
+    let page = client
+        .post(&url, &[("level", &level), ("answer", answer)])?
+        .into_body()
+        .read_to_string()
+        .map_err(FetchError::from)?;
+    let response = Response::parse(&page);
+
+    let mistake = match response {
+        Response::Correct => {
+            answers::record(dir, year, day, part, answer)?;
+            None
+        }
+        Response::TooHigh => Some(Mistake::TooHigh),
+        Response::TooLow => Some(Mistake::TooLow),
+        Response::Wrong => Some(Mistake::Wrong),
+        _ => None,
+    };
+    if let Some(mistake) = mistake {
+        guesses.add(part, mistake, answer);
+        let path = guesses_path(dir, year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, guesses.to_string())?;
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::Config, stand_in};
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+        assert_eq!(
+            Response::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Response::Correct
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Response::TooHigh
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Response::TooLow
+        );
+        assert_eq!(
+            Response::parse(&page("That's not the right answer. If you're stuck...")),
+            Response::Wrong
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Response::Wait(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Response::parse(&page(
+                "You gave an answer too recently. You have 30s left to wait."
+            )),
+            Response::Wait(Some(Duration::from_secs(30)))
+        );
+        assert_eq!(
+            Response::parse(&page("You don't seem to be solving the right level.")),
+            Response::WrongLevel
+        );
+        assert!(matches!(
+            Response::parse("<html></html>"),
+            Response::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn test_guesses() {
+        let guesses = Guesses::parse("1 high 100\n1 low 10\n1 wrong 50\n2 wrong abc\n");
+        assert_eq!(Guesses::parse(&guesses.to_string()), guesses);
+
+        assert!(guesses.refuse(1, "100").is_some());
+        assert!(guesses.refuse(1, "150").is_some());
+        assert!(guesses.refuse(1, "10").is_some());
+        assert!(guesses.refuse(1, "5").is_some());
+        assert!(guesses.refuse(1, "50").is_some());
+        assert_eq!(guesses.refuse(1, "42"), None);
+        assert_eq!(guesses.refuse(2, "100"), None);
+        assert!(guesses.refuse(2, "abc").is_some());
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = std::env::temp_dir().join(format!("aoc_submit_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let (base_url, requests) = stand_in::serve(vec![
+            (
+                200,
+                "<p>That's not the right answer; your answer is too low.</p>",
+            ),
+            (200, "<p>That's the right answer!</p>"),
+        ]);
+        let config = Config {
+            session: Some("token".to_string()),
+            base_url,
+            rate_limit: Duration::ZERO,
+        };
+        let client = Client::new(&config, &dir).unwrap();
+
+        assert_eq!(
+            submit(&client, &dir, 2023, 5, 1, "10").unwrap(),
+            Response::TooLow
+        );
+        let request = requests.recv().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/2023/day/5/answer");
+        assert_eq!(request.body, "level=1&answer=10");
+
+        assert!(matches!(
+            submit(&client, &dir, 2023, 5, 1, "7"),
+            Err(SubmitError::Refused(_))
+        ));
+
+        assert_eq!(
+            submit(&client, &dir, 2023, 5, 1, "42").unwrap(),
+            Response::Correct
+        );
+        assert_eq!(answers::load(&dir, 2023, 5).get(1), Some("42"));
+        assert_eq!(load(&dir, 2023, 5).to_string(), "1 low 10\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,50 +1,31 @@
-use std::time::Instant;
+// https://adventofcode.com/{{year}}/day/{{day}}
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+use crate::solution::{Answer, Solution};
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
-#[tracing::instrument(skip(input))]
-fn func1(input: &str) -> u32 {
+#[tracing::instrument(skip(_input))]
+fn func1(_input: &str) -> u32 {
     todo!()
 }
 
-#[tracing::instrument(skip(input))]
-fn func2(input: &str) -> u32 {
+#[tracing::instrument(skip(_input))]
+fn func2(_input: &str) -> u32 {
     todo!()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::rstest;
-    use test_log::test;
-
-    /*
-    #[rstest]
-    #[case()]
-    fn test_func1(#[case] expected: u32, #[case] input: &str) {}
-    */
-
-    #[test]
-    fn test_func1() {
-        let input = "";
-        let expected = 0;
-
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "";
-        let expected = 0;
-
-        assert_eq!(func2(input), expected);
-    }
-}
+// `aoc fetch {{year}} {{day}} --examples` stores the examples of the puzzle in
+// `y{{year}}/day{{day:02}}.examples/` next to the input. Write the expected answers of each
+// one in its `.answers` file and a test gets generated for it, see `src/examples.rs`. Only
+// examples that need a different parameter than the real input (a smaller grid, fewer steps)
+// are left as a `#[cfg(test)]` test calling the function directly.
@@ -1,6 +1,15 @@
 pub mod coords;
+pub mod coords_n;
+pub mod cycles;
 pub mod direction;
+pub mod dsu;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod hex;
+pub mod intervals;
 pub mod io;
-pub mod parser;
-pub mod transposer;
 pub mod multiset;
+pub mod parser;
+pub mod search;
+pub mod sparse_grid;
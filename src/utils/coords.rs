@@ -1,12 +1,37 @@
 use std::{
     collections::HashSet,
     hash::Hash,
-    ops::{Add, Sub},
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
 };
 
+use glam::{I64Vec2, IVec2, U64Vec2, UVec2};
 use num::{CheckedAdd, CheckedSub, Integer, Num, Signed};
 
-use super::direction::Direction;
+use super::direction::{Compass, Direction};
+
+/// Which way `Direction::Up` goes along the y axis.
+///
+/// Coordinates follow the screen convention everywhere: x grows to the right, y grows downward
+/// and the origin is the top-left corner, so rows of the input are `y` and `up` decrements y.
+/// Puzzles stated in the math convention (y grows upward) can step with [`Convention::Math`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Convention {
+    #[default]
+    Screen,
+    Math,
+}
+
+impl Convention {
+    /// The screen direction moving like `direction` does in this convention.
+    pub fn screen_direction(self, direction: Direction) -> Direction {
+        match (self, direction) {
+            (Convention::Math, Direction::Up) => Direction::Down,
+            (Convention::Math, Direction::Down) => Direction::Up,
+            (_, direction) => direction,
+        }
+    }
+}
 
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub struct Coordinates<T>
@@ -36,8 +61,27 @@ where
     pub fn y(&self) -> T {
         self.y
     }
+
+    pub fn with_x(&self, x: T) -> Self {
+        Self::new(x, self.y)
+    }
+
+    pub fn with_y(&self, y: T) -> Self {
+        Self::new(self.x, y)
+    }
+
+    pub fn dot(&self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// The z component of the 3-D cross product, positive when `other` is clockwise from `self`
+    /// on screen.
+    pub fn cross(&self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
 }
 
+/// Screen convention: `up` decrements y and `down` increments it.
 impl<T> Coordinates<T>
 where
     T: Integer + Signed + Copy,
@@ -48,8 +92,7 @@ where
             y: self.y - num::one(),
         }
     }
-
-    fn up_by(&self, steps: T) -> Self {
+    pub fn up_by(&self, steps: T) -> Self {
         Self {
             x: self.x,
             y: self.y - steps,
@@ -61,7 +104,7 @@ where
             y: self.y + num::one(),
         }
     }
-    fn down_by(&self, steps: T) -> Self {
+    pub fn down_by(&self, steps: T) -> Self {
         Self {
             x: self.x,
             y: self.y + steps,
@@ -74,7 +117,7 @@ where
             y: self.y,
         }
     }
-    fn left_by(&self, steps: T) -> Self {
+    pub fn left_by(&self, steps: T) -> Self {
         Self {
             x: self.x - steps,
             y: self.y,
@@ -87,7 +130,7 @@ where
             y: self.y,
         }
     }
-    fn right_by(&self, steps: T) -> Self {
+    pub fn right_by(&self, steps: T) -> Self {
         Self {
             x: self.x + steps,
             y: self.y,
@@ -95,18 +138,19 @@ where
     }
 }
 
+/// Same moves as `up`, `down`... for unsigned coordinates, `None` when leaving the type's range.
 impl<T> Coordinates<T>
 where
     T: Integer + CheckedAdd + CheckedSub + Copy,
 {
     pub fn try_up(&self) -> Option<Self> {
         self.y
-            .checked_add(&num::one())
+            .checked_sub(&num::one())
             .map(|y| Self { x: self.x, y })
     }
     pub fn try_down(&self) -> Option<Self> {
         self.y
-            .checked_sub(&num::one())
+            .checked_add(&num::one())
             .map(|y| Self { x: self.x, y })
     }
     pub fn try_right(&self) -> Option<Self> {
@@ -120,7 +164,6 @@ where
             .map(|x| Self { x, y: self.y })
     }
 
-
     pub fn try_step(&self, direction: Direction) -> Option<Self> {
         match direction {
             Direction::Up => self.try_up(),
@@ -129,6 +172,16 @@ where
             Direction::Right => self.try_right(),
         }
     }
+
+    pub fn try_step_in(&self, direction: Direction, convention: Convention) -> Option<Self> {
+        self.try_step(convention.screen_direction(direction))
+    }
+
+    pub fn try_step_compass(&self, compass: Compass) -> Option<Self> {
+        compass
+            .directions()
+            .try_fold(*self, |coord, direction| coord.try_step(direction))
+    }
 }
 
 impl<T> Coordinates<T>
@@ -180,7 +233,7 @@ where
 
 impl<T> Coordinates<T>
 where
-    T: Integer + Signed + Copy + CheckedAdd + CheckedSub ,
+    T: Integer + Signed + Copy,
 {
     pub fn step(&self, direction: Direction) -> Self {
         match direction {
@@ -199,6 +252,20 @@ where
             Direction::Right => self.right_by(steps),
         }
     }
+
+    pub fn step_compass(&self, compass: Compass) -> Self {
+        compass
+            .directions()
+            .fold(*self, |coord, direction| coord.step(direction))
+    }
+
+    pub fn step_in(&self, direction: Direction, convention: Convention) -> Self {
+        self.step(convention.screen_direction(direction))
+    }
+
+    pub fn step_by_in(&self, direction: Direction, steps: T, convention: Convention) -> Self {
+        self.step_by(convention.screen_direction(direction), steps)
+    }
 }
 
 impl<T> Coordinates<T>
@@ -218,6 +285,32 @@ where
         };
         x + y
     }
+
+    pub fn chebyshev_distance(&self, other: Self) -> T {
+        let delta = *self - other;
+        delta.x.abs().max(delta.y.abs())
+    }
+
+    pub fn euclidean_distance_squared(&self, other: Self) -> T {
+        let delta = *self - other;
+        delta.dot(delta)
+    }
+
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Quarter turn clockwise on screen around `center`: up becomes right.
+    pub fn rotate_clockwise_around(&self, center: Self) -> Self {
+        let delta = *self - center;
+        center + Self::new(-delta.y, delta.x)
+    }
+
+    /// Quarter turn counterclockwise on screen around `center`: up becomes left.
+    pub fn rotate_counterclockwise_around(&self, center: Self) -> Self {
+        let delta = *self - center;
+        center + Self::new(delta.y, -delta.x)
+    }
 }
 
 impl<T> Sub for Coordinates<T>
@@ -248,15 +341,293 @@ where
     }
 }
 
+impl<T> AddAssign for Coordinates<T>
+where
+    T: Num + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x = self.x + rhs.x;
+        self.y = self.y + rhs.y;
+    }
+}
+
+impl<T> SubAssign for Coordinates<T>
+where
+    T: Num + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x = self.x - rhs.x;
+        self.y = self.y - rhs.y;
+    }
+}
+
+impl<T> Mul<T> for Coordinates<T>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Coordinates<T>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl<T> Neg for Coordinates<T>
+where
+    T: Num + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// `TryFrom` in both directions between every pair of integer widths.
+macro_rules! impl_try_from {
+    () => {};
+    ($head:ty $(, $tail:ty)*) => {
+        $(
+            impl_try_from!(@impl $head => $tail);
+            impl_try_from!(@impl $tail => $head);
+        )*
+        impl_try_from!($($tail),*);
+    };
+    (@impl $from:ty => $to:ty) => {
+        impl TryFrom<Coordinates<$from>> for Coordinates<$to> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: Coordinates<$from>) -> Result<Self, Self::Error> {
+                Ok(Self::new(value.x.try_into()?, value.y.try_into()?))
+            }
+        }
+    };
+}
+
+impl_try_from!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Conversions from and to the `glam` vector with the same element type.
+macro_rules! impl_glam {
+    ($($vec:ty => $t:ty),*) => {
+        $(
+            impl From<$vec> for Coordinates<$t> {
+                fn from(value: $vec) -> Self {
+                    Self::new(value.x, value.y)
+                }
+            }
+
+            impl From<Coordinates<$t>> for $vec {
+                fn from(value: Coordinates<$t>) -> Self {
+                    <$vec>::new(value.x, value.y)
+                }
+            }
+        )*
+    };
+}
+
+impl_glam!(IVec2 => i32, I64Vec2 => i64, UVec2 => u32, U64Vec2 => u64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    // #[test]
-    // fn orthogonal_distance() {
-    //     let coord1: Coordinates<i32> = Coordinates::new(1, 5);
-    //     let coord2: Coordinates<i32> = Coordinates::new(4, 9);
-    //
-    //     assert_eq!(7, coord1.orthogonal_distance(coord2));
-    // }
+    #[test]
+    fn orthogonal_distance() {
+        let coord1: Coordinates<i32> = Coordinates::new(1, 5);
+        let coord2: Coordinates<i32> = Coordinates::new(4, 9);
+
+        assert_eq!(7, coord1.orthogonal_distance(coord2));
+    }
+
+    #[test]
+    fn test_screen_convention() {
+        let coord: Coordinates<i32> = Coordinates::new(3, 5);
+
+        assert_eq!(coord.up(), Coordinates::new(3, 4));
+        assert_eq!(coord.down(), Coordinates::new(3, 6));
+        assert_eq!(coord.left(), Coordinates::new(2, 5));
+        assert_eq!(coord.right(), Coordinates::new(4, 5));
+        assert_eq!(coord.up_by(2), Coordinates::new(3, 3));
+        assert_eq!(coord.down_by(2), Coordinates::new(3, 7));
+        assert_eq!(coord.left_by(2), Coordinates::new(1, 5));
+        assert_eq!(coord.right_by(2), Coordinates::new(5, 5));
+
+        for direction in Direction::ALL {
+            let offset: Coordinates<i32> = direction.into();
+            assert_eq!(coord.step(direction), coord + offset);
+            assert_eq!(
+                coord.step_by(direction, 4),
+                coord + offset + offset + offset + offset
+            );
+            assert_eq!(coord.try_step(direction), Some(coord.step(direction)));
+        }
+        assert_eq!(
+            Coordinates::<i32>::from(Direction::Up),
+            Coordinates::new(0, -1)
+        );
+        assert_eq!(
+            Coordinates::<i32>::from(Direction::Down),
+            Coordinates::new(0, 1)
+        );
+        assert_eq!(
+            Coordinates::<i32>::from(Direction::Left),
+            Coordinates::new(-1, 0)
+        );
+        assert_eq!(
+            Coordinates::<i32>::from(Direction::Right),
+            Coordinates::new(1, 0)
+        );
+    }
+
+    #[test]
+    fn test_try_moves() {
+        let coord: Coordinates<usize> = Coordinates::new(1, 1);
+
+        assert_eq!(coord.try_up(), Some(Coordinates::new(1, 0)));
+        assert_eq!(coord.try_down(), Some(Coordinates::new(1, 2)));
+        assert_eq!(coord.try_left(), Some(Coordinates::new(0, 1)));
+        assert_eq!(coord.try_right(), Some(Coordinates::new(2, 1)));
+        assert_eq!(coord.try_step(Direction::Up), coord.try_up());
+        assert_eq!(coord.try_step(Direction::Down), coord.try_down());
+
+        let origin: Coordinates<usize> = Coordinates::origin();
+        assert_eq!(origin.try_up(), None);
+        assert_eq!(origin.try_left(), None);
+        assert_eq!(
+            origin.diagonal_neighbors(),
+            HashSet::from([Coordinates::new(1, 1)])
+        );
+        assert_eq!(Coordinates::new(usize::MAX, usize::MAX).try_down(), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut coord: Coordinates<i32> = Coordinates::new(3, -4);
+
+        assert_eq!(coord * 2, Coordinates::new(6, -8));
+        assert_eq!(coord / 2, Coordinates::new(1, -2));
+        assert_eq!(-coord, Coordinates::new(-3, 4));
+        assert_eq!(coord.signum(), Coordinates::new(1, -1));
+        assert_eq!(coord.dot(Coordinates::new(2, 1)), 2);
+        assert_eq!(coord.cross(Coordinates::new(2, 1)), 11);
+        assert_eq!(coord.with_x(0), Coordinates::new(0, -4));
+        assert_eq!(coord.with_y(0), Coordinates::new(3, 0));
+
+        coord += Coordinates::new(1, 1);
+        assert_eq!(coord, Coordinates::new(4, -3));
+        coord -= Coordinates::new(4, -3);
+        assert_eq!(coord, Coordinates::origin());
+    }
+
+    #[test]
+    fn test_distances() {
+        let a: Coordinates<i64> = Coordinates::new(1, 5);
+        let b = Coordinates::new(4, 9);
+
+        assert_eq!(a.orthogonal_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.euclidean_distance_squared(b), 25);
+        assert_eq!(b.chebyshev_distance(a), 4);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let center: Coordinates<i32> = Coordinates::new(2, 2);
+        let up = center.up();
+
+        assert_eq!(up.rotate_clockwise_around(center), center.right());
+        assert_eq!(up.rotate_counterclockwise_around(center), center.left());
+        assert_eq!(
+            Coordinates::new(5, 3).rotate_clockwise_around(center),
+            Coordinates::new(1, 5)
+        );
+        let mut point = Coordinates::new(5, 3);
+        for _ in 0..4 {
+            point = point.rotate_counterclockwise_around(center);
+        }
+        assert_eq!(point, Coordinates::new(5, 3));
+        for direction in Direction::ALL {
+            let offset: Coordinates<i32> = direction.into();
+            let turned: Coordinates<i32> = direction.turn_right().into();
+            assert_eq!(
+                offset.rotate_clockwise_around(Coordinates::origin()),
+                turned
+            );
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let wide: Coordinates<i64> = Coordinates::new(3, 4);
+        assert_eq!(
+            Coordinates::<u8>::try_from(wide),
+            Ok(Coordinates::new(3, 4))
+        );
+        assert!(Coordinates::<u8>::try_from(Coordinates::new(-1i64, 0)).is_err());
+        assert!(Coordinates::<u8>::try_from(Coordinates::new(0usize, 256)).is_err());
+        assert_eq!(
+            Coordinates::<i64>::try_from(Coordinates::new(3u8, 4)),
+            Ok(wide)
+        );
+
+        assert_eq!(
+            Coordinates::from(IVec2::new(1, -2)),
+            Coordinates::new(1, -2)
+        );
+        assert_eq!(U64Vec2::from(Coordinates::new(1u64, 2)), U64Vec2::new(1, 2));
+    }
+
+    #[test]
+    fn test_math_convention() {
+        let coord: Coordinates<i32> = Coordinates::new(3, 5);
+
+        assert_eq!(
+            coord.step_in(Direction::Up, Convention::Math),
+            Coordinates::new(3, 6)
+        );
+        assert_eq!(
+            coord.step_in(Direction::Down, Convention::Math),
+            Coordinates::new(3, 4)
+        );
+        assert_eq!(
+            coord.step_in(Direction::Left, Convention::Math),
+            coord.left()
+        );
+        assert_eq!(
+            coord.step_in(Direction::Right, Convention::Math),
+            coord.right()
+        );
+        assert_eq!(
+            coord.step_by_in(Direction::Up, 3, Convention::Math),
+            Coordinates::new(3, 8)
+        );
+        assert_eq!(coord.step_in(Direction::Up, Convention::Screen), coord.up());
+
+        let origin: Coordinates<u32> = Coordinates::origin();
+        assert_eq!(origin.try_step_in(Direction::Down, Convention::Math), None);
+        assert_eq!(
+            origin.try_step_in(Direction::Up, Convention::Math),
+            Some(Coordinates::new(0, 1))
+        );
+    }
 }
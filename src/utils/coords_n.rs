@@ -0,0 +1,427 @@
+//! Coordinates with any number of axes, for the 3-D puzzles and beyond. They share the API of
+//! the 2-D [`Coordinates`]: the same arithmetic, distances and neighbourhoods, where a point has
+//! `2 * N` orthogonal neighbours and `3^N - 1` neighbours in total (6 and 26 in 3-D).
+
+use std::{
+    array,
+    collections::HashSet,
+    hash::Hash,
+    num::TryFromIntError,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+use glam::{I64Vec3, IVec3, U64Vec3, UVec3};
+use num::{CheckedAdd, CheckedSub, Integer, Num, Signed};
+
+use super::coords::Coordinates;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct CoordinatesN<T, const N: usize>
+where
+    T: Num,
+{
+    axes: [T; N],
+}
+
+pub type Coordinates3<T> = CoordinatesN<T, 3>;
+
+impl<T, const N: usize> CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    pub fn from_axes(axes: [T; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn origin() -> Self {
+        Self::from_axes([num::zero(); N])
+    }
+
+    pub fn axes(&self) -> [T; N] {
+        self.axes
+    }
+
+    pub fn axis(&self, axis: usize) -> T {
+        self.axes[axis]
+    }
+
+    pub fn with_axis(&self, axis: usize, value: T) -> Self {
+        let mut axes = self.axes;
+        axes[axis] = value;
+        Self::from_axes(axes)
+    }
+
+    pub fn dot(&self, other: Self) -> T {
+        self.axes
+            .iter()
+            .zip(other.axes)
+            .fold(num::zero(), |acc, (&a, b)| acc + a * b)
+    }
+
+    fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        Self::from_axes(array::from_fn(|i| f(self.axes[i], other.axes[i])))
+    }
+
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        Self::from_axes(self.axes.map(f))
+    }
+}
+
+impl<T> Coordinates3<T>
+where
+    T: Num + Copy,
+{
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::from_axes([x, y, z])
+    }
+
+    pub fn x(&self) -> T {
+        self.axes[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.axes[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.axes[2]
+    }
+
+    pub fn cross(&self, other: Self) -> Self {
+        let [x, y, z] = self.axes;
+        let [ox, oy, oz] = other.axes;
+        Self::new(y * oz - z * oy, z * ox - x * oz, x * oy - y * ox)
+    }
+}
+
+/// Neighbours that don't fit the type, like below zero for unsigned axes, are left out.
+impl<T, const N: usize> CoordinatesN<T, N>
+where
+    T: Integer + Hash + CheckedAdd + CheckedSub + Copy,
+{
+    /// Every point whose axes differ by at most one, with `moved` axes actually moving.
+    fn neighbors_where(&self, moved: impl Fn(usize) -> bool) -> HashSet<Self> {
+        let mut neighbors = HashSet::new();
+        for offsets in 0..3usize.pow(N as u32) {
+            let deltas: [usize; N] = array::from_fn(|i| offsets / 3usize.pow(i as u32) % 3);
+            if !moved(deltas.iter().filter(|&&delta| delta != 1).count()) {
+                continue;
+            }
+
+            let mut axes = self.axes;
+            let fits = axes.iter_mut().zip(deltas).all(|(axis, delta)| {
+                let moved = match delta {
+                    0 => axis.checked_sub(&num::one()),
+                    2 => axis.checked_add(&num::one()),
+                    _ => Some(*axis),
+                };
+                moved.map(|moved| *axis = moved).is_some()
+            });
+            if fits {
+                neighbors.insert(Self::from_axes(axes));
+            }
+        }
+        neighbors
+    }
+
+    /// The neighbours sharing a face: 6 in 3-D.
+    pub fn orthogonal_neighbors(&self) -> HashSet<Self> {
+        self.neighbors_where(|moved| moved == 1)
+    }
+
+    /// The neighbours sharing only an edge or a corner: 20 in 3-D.
+    pub fn diagonal_neighbors(&self) -> HashSet<Self> {
+        self.neighbors_where(|moved| moved > 1)
+    }
+
+    /// The neighbours sharing a face, an edge or a corner: 26 in 3-D.
+    pub fn all_neighbors(&self) -> HashSet<Self> {
+        self.neighbors_where(|moved| moved > 0)
+    }
+}
+
+impl<T, const N: usize> CoordinatesN<T, N>
+where
+    T: Integer + Signed + Copy,
+{
+    pub fn orthogonal_distance(&self, other: Self) -> T {
+        (*self - other)
+            .axes
+            .iter()
+            .fold(num::zero(), |acc, delta| acc + delta.abs())
+    }
+
+    pub fn chebyshev_distance(&self, other: Self) -> T {
+        (*self - other)
+            .axes
+            .iter()
+            .fold(num::zero(), |acc, delta| acc.max(delta.abs()))
+    }
+
+    pub fn euclidean_distance_squared(&self, other: Self) -> T {
+        let delta = *self - other;
+        delta.dot(delta)
+    }
+
+    pub fn signum(&self) -> Self {
+        self.map(|axis| axis.signum())
+    }
+}
+
+impl<T, const N: usize> Index<usize> for CoordinatesN<T, N>
+where
+    T: Num,
+{
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.axes[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for CoordinatesN<T, N>
+where
+    T: Num,
+{
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.axes[axis]
+    }
+}
+
+impl<T, const N: usize> Add for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T, const N: usize> Sub for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T, const N: usize> AddAssign for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, const N: usize> SubAssign for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T, const N: usize> Mul<T> for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|axis| axis * rhs)
+    }
+}
+
+impl<T, const N: usize> Div<T> for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|axis| axis / rhs)
+    }
+}
+
+impl<T, const N: usize> Neg for CoordinatesN<T, N>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|axis| -axis)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for CoordinatesN<T, N>
+where
+    T: Num + Copy,
+{
+    fn from(axes: [T; N]) -> Self {
+        Self::from_axes(axes)
+    }
+}
+
+impl<T> From<Coordinates<T>> for CoordinatesN<T, 2>
+where
+    T: Num + Copy,
+{
+    fn from(value: Coordinates<T>) -> Self {
+        Self::from_axes([value.x(), value.y()])
+    }
+}
+
+impl<T> From<CoordinatesN<T, 2>> for Coordinates<T>
+where
+    T: Num + Copy,
+{
+    fn from(value: CoordinatesN<T, 2>) -> Self {
+        Coordinates::new(value.axes[0], value.axes[1])
+    }
+}
+
+/// `TryFrom` in both directions between every pair of integer widths.
+macro_rules! impl_try_from {
+    () => {};
+    ($head:ty $(, $tail:ty)*) => {
+        $(
+            impl_try_from!(@impl $head => $tail);
+            impl_try_from!(@impl $tail => $head);
+        )*
+        impl_try_from!($($tail),*);
+    };
+    (@impl $from:ty => $to:ty) => {
+        impl<const N: usize> TryFrom<CoordinatesN<$from, N>> for CoordinatesN<$to, N> {
+            type Error = TryFromIntError;
+
+            fn try_from(value: CoordinatesN<$from, N>) -> Result<Self, Self::Error> {
+                let mut axes = [0; N];
+                for (axis, value) in axes.iter_mut().zip(value.axes) {
+                    *axis = value.try_into()?;
+                }
+                Ok(Self::from_axes(axes))
+            }
+        }
+    };
+}
+
+impl_try_from!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Conversions from and to the `glam` vector with the same element type.
+macro_rules! impl_glam {
+    ($($vec:ty => $t:ty),*) => {
+        $(
+            impl From<$vec> for Coordinates3<$t> {
+                fn from(value: $vec) -> Self {
+                    Self::new(value.x, value.y, value.z)
+                }
+            }
+
+            impl From<Coordinates3<$t>> for $vec {
+                fn from(value: Coordinates3<$t>) -> Self {
+                    <$vec>::new(value.x(), value.y(), value.z())
+                }
+            }
+        )*
+    };
+}
+
+impl_glam!(IVec3 => i32, I64Vec3 => i64, UVec3 => u32, U64Vec3 => u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_neighbors() {
+        let point: Coordinates3<i32> = Coordinates3::new(1, 1, 1);
+
+        assert_eq!(point.orthogonal_neighbors().len(), 6);
+        assert_eq!(point.diagonal_neighbors().len(), 20);
+        assert_eq!(point.all_neighbors().len(), 26);
+        assert!(point
+            .orthogonal_neighbors()
+            .contains(&Coordinates3::new(1, 1, 0)));
+        assert!(point.all_neighbors().contains(&Coordinates3::new(0, 2, 0)));
+        assert!(!point.all_neighbors().contains(&point));
+
+        let corner: Coordinates3<u8> = Coordinates3::origin();
+        assert_eq!(corner.orthogonal_neighbors().len(), 3);
+        assert_eq!(corner.all_neighbors().len(), 7);
+
+        let hyper: CoordinatesN<i64, 4> = CoordinatesN::origin();
+        assert_eq!(hyper.orthogonal_neighbors().len(), 8);
+        assert_eq!(hyper.all_neighbors().len(), 80);
+
+        let flat: Coordinates<i64> = Coordinates::new(2, 3);
+        let as_n: CoordinatesN<i64, 2> = flat.into();
+        assert_eq!(
+            as_n.all_neighbors(),
+            flat.all_neighbors()
+                .into_iter()
+                .map(CoordinatesN::from)
+                .collect()
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a: Coordinates3<i64> = Coordinates3::new(1, 5, -2);
+        let b = Coordinates3::new(4, 9, 0);
+
+        assert_eq!(a.orthogonal_distance(b), 9);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(a.euclidean_distance_squared(b), 29);
+        assert_eq!(b.orthogonal_distance(a), 9);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point: Coordinates3<i32> = Coordinates3::new(3, -4, 2);
+
+        assert_eq!(point * 2, Coordinates3::new(6, -8, 4));
+        assert_eq!(point / 2, Coordinates3::new(1, -2, 1));
+        assert_eq!(-point, Coordinates3::new(-3, 4, -2));
+        assert_eq!(point.signum(), Coordinates3::new(1, -1, 1));
+        assert_eq!(point.dot(Coordinates3::new(1, 1, 1)), 1);
+        assert_eq!(
+            Coordinates3::new(1, 0, 0).cross(Coordinates3::new(0, 1, 0)),
+            Coordinates3::new(0, 0, 1)
+        );
+        assert_eq!(point.with_axis(2, 0), Coordinates3::new(3, -4, 0));
+        assert_eq!(point[1], -4);
+
+        point += Coordinates3::new(1, 1, 1);
+        assert_eq!(point, Coordinates3::new(4, -3, 3));
+        point -= Coordinates3::new(4, -3, 3);
+        assert_eq!(point, Coordinates3::origin());
+    }
+
+    #[test]
+    fn test_conversions() {
+        let wide: Coordinates3<i64> = Coordinates3::new(3, 4, 5);
+        assert_eq!(
+            Coordinates3::<u8>::try_from(wide),
+            Ok(Coordinates3::new(3, 4, 5))
+        );
+        assert!(Coordinates3::<u8>::try_from(Coordinates3::new(0i64, -1, 0)).is_err());
+
+        assert_eq!(
+            Coordinates3::from(IVec3::new(1, -2, 3)),
+            Coordinates3::new(1, -2, 3)
+        );
+        assert_eq!(
+            IVec3::from(Coordinates3::new(1, -2, 3)),
+            IVec3::new(1, -2, 3)
+        );
+        assert_eq!(CoordinatesN::from([1, 2, 3, 4]).axis(3), 4);
+    }
+}
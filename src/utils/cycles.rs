@@ -0,0 +1,247 @@
+//! Fast-forwarding simulations that end up repeating themselves, and combining independent
+//! periodic signals.
+
+use std::{collections::HashMap, hash::Hash};
+
+use num::{Integer, Signed};
+
+/// Where a sequence of states starts repeating: from step `start` on, the state at step
+/// `i + period` is the state at step `i`, step 0 being the initial state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+/// Finds the cycle by remembering every state in a hash map, which is fast but keeps them all
+/// in memory. See [`brent`] when the states are large.
+pub fn detect<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    for i in 0.. {
+        if let Some(&start) = seen.get(&state) {
+            return Cycle {
+                start,
+                period: i - start,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state, i);
+        state = next;
+    }
+    unreachable!()
+}
+
+/// Finds the cycle with Brent's algorithm, keeping only two states in memory at the cost of
+/// stepping about three times as much as [`detect`].
+pub fn brent<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle
+where
+    S: Clone + Eq,
+{
+    // Find the period by moving the hare ahead of a tortoise teleported to it at every power
+    // of two.
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Then the start, with the hare one period ahead of the tortoise.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// The state after `n` steps, without simulating past the first repetition.
+pub fn state_at<S>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    for i in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                period: i - start,
+            };
+            return states.swap_remove(cycle.equivalent_step(n));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), i);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+/// The first step at which signals firing every `period` steps all fire together.
+pub fn lcm_all<T>(periods: impl IntoIterator<Item = T>) -> T
+where
+    T: Integer + Copy,
+{
+    periods
+        .into_iter()
+        .fold(T::one(), |acc, period| acc.lcm(&period))
+}
+
+/// Solves `x ≡ remainder (mod modulus)` for every pair with the Chinese remainder theorem,
+/// moduli sharing factors included. Returns the smallest non-negative `x` with the modulus
+/// of the solutions, or `None` when the congruences contradict each other.
+pub fn crt<T>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)>
+where
+    T: Integer + Signed + Copy,
+{
+    congruences
+        .into_iter()
+        .try_fold((T::zero(), T::one()), |(a1, m1), (a2, m2)| {
+            let a2 = a2.mod_floor(&m2);
+            let gcd = m1.extended_gcd(&m2);
+            let (difference, rest) = (a2 - a1).div_rem(&gcd.gcd);
+            if !rest.is_zero() {
+                return None;
+            }
+            let lcm = m1 / gcd.gcd * m2;
+            let k = (difference * gcd.x).mod_floor(&(m2 / gcd.gcd));
+            Some(((a1 + k * m1).mod_floor(&lcm), lcm))
+        })
+}
+
+/// The first step, from step 0 on, at which every signal fires, a signal firing at `offset`
+/// and then every `period` steps.
+pub fn first_common_step<T>(signals: impl IntoIterator<Item = (T, T)>) -> Option<T>
+where
+    T: Integer + Signed + Copy,
+{
+    let signals: Vec<_> = signals.into_iter().collect();
+    let latest = signals
+        .iter()
+        .map(|&(offset, _)| offset)
+        .max()
+        .unwrap_or_else(T::zero);
+    let (x, modulus) = crt(signals.iter().copied())?;
+    if x >= latest {
+        return Some(x);
+    }
+    let laps = (latest - x).div_ceil(&modulus);
+    Some(x + laps * modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 3, 4, 5...
+    fn step(n: &u32) -> u32 {
+        if *n == 5 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn test_detect() {
+        let cycle = Cycle {
+            start: 3,
+            period: 3,
+        };
+        assert_eq!(detect(0, step), cycle);
+        assert_eq!(brent(0, step), cycle);
+        assert_eq!(
+            detect(4, step),
+            Cycle {
+                start: 0,
+                period: 3
+            }
+        );
+        assert_eq!(
+            brent(4, step),
+            Cycle {
+                start: 0,
+                period: 3
+            }
+        );
+        assert_eq!(
+            brent(7, |_| 7),
+            Cycle {
+                start: 0,
+                period: 1
+            }
+        );
+
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(7), 4);
+    }
+
+    #[test]
+    fn test_state_at() {
+        let mut sequence = vec![0];
+        for _ in 0..100 {
+            sequence.push(step(sequence.last().unwrap()));
+        }
+        for (n, &expected) in sequence.iter().enumerate() {
+            assert_eq!(state_at(0, step, n), expected, "step {n}");
+        }
+        assert_eq!(state_at(0, step, 1_000_000_000), 4);
+
+        let mut calls = 0;
+        state_at(
+            0,
+            |n| {
+                calls += 1;
+                step(n)
+            },
+            1_000_000_000,
+        );
+        assert_eq!(calls, 6);
+    }
+
+    #[test]
+    fn test_combine() {
+        assert_eq!(lcm_all([2, 3, 4]), 12);
+        assert_eq!(lcm_all(Vec::<u64>::new()), 1);
+
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1i64, 5)]), Some((4, 5)));
+
+        assert_eq!(first_common_step([(0, 4), (0, 6)]), Some(0));
+        assert_eq!(first_common_step([(8, 4), (6, 6)]), Some(12));
+        assert_eq!(first_common_step([(1i128, 2), (0, 2)]), None);
+    }
+}
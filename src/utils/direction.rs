@@ -1,4 +1,7 @@
-use std::fmt::{Display, Formatter};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
 use num::{Integer, Signed};
 
@@ -13,13 +16,47 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// In the order of [`Direction::index`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Reads the usual puzzle notations: `U`/`D`/`L`/`R`, `N`/`S`/`W`/`E` and `^`/`v`/`<`/`>`,
+    /// in either case.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c.to_ascii_uppercase() {
+            'U' | 'N' | '^' => Some(Direction::Up),
+            'D' | 'S' | 'V' => Some(Direction::Down),
+            'L' | 'W' | '<' => Some(Direction::Left),
+            'R' | 'E' | '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Same as [`Direction::from_char`], also accepting the names of the directions.
+    pub fn parse(notation: &str) -> Option<Direction> {
+        let mut chars = notation.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Direction::from_char(c);
+        }
+        match notation.to_ascii_lowercase().as_str() {
+            "up" | "north" => Some(Direction::Up),
+            "down" | "south" => Some(Direction::Down),
+            "left" | "west" => Some(Direction::Left),
+            "right" | "east" => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
     pub fn get_directions() -> Vec<Direction> {
-        return vec![
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ];
+        Direction::ALL.to_vec()
     }
 
     pub fn reverse_direction(&self) -> Direction {
@@ -31,7 +68,6 @@ impl Direction {
         }
     }
 
-
     pub fn turn_right(&self) -> Direction {
         match self {
             Direction::Up => Direction::Right,
@@ -41,7 +77,6 @@ impl Direction {
         }
     }
 
-
     pub fn turn_left(&self) -> Direction {
         match self {
             Direction::Up => Direction::Left,
@@ -64,12 +99,142 @@ impl Display for Direction {
     }
 }
 
-impl<T> Into<Coordinates<T>> for Direction
+/// The inverse of [`Direction::index`], failing past the four directions.
+impl TryFrom<u8> for Direction {
+    type Error = DirectionIndexError;
+
+    fn try_from(index: u8) -> Result<Self, Self::Error> {
+        Direction::ALL
+            .get(index as usize)
+            .copied()
+            .ok_or(DirectionIndexError(index))
+    }
+}
+
+impl From<Direction> for u8 {
+    fn from(direction: Direction) -> Self {
+        direction as u8
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not a direction: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectionIndexError(u8);
+
+impl Display for DirectionIndexError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "no direction has index {}", self.0)
+    }
+}
+
+impl std::error::Error for DirectionIndexError {}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Direction::parse(s).ok_or_else(|| ParseDirectionError(s.to_string()))
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Direction::from_char(c).ok_or_else(|| ParseDirectionError(c.to_string()))
+    }
+}
+
+/// A set of directions in one byte, e.g. to remember which ways a beam already went through a
+/// cell.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    pub const EMPTY: DirectionSet = DirectionSet(0);
+    pub const ALL: DirectionSet = DirectionSet(0b1111);
+
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    fn bit(direction: Direction) -> u8 {
+        1 << direction.index()
+    }
+
+    /// Returns whether the direction was not in the set yet.
+    pub fn insert(&mut self, direction: Direction) -> bool {
+        let added = !self.contains(direction);
+        self.0 |= Self::bit(direction);
+        added
+    }
+
+    /// Returns whether the direction was in the set.
+    pub fn remove(&mut self, direction: Direction) -> bool {
+        let removed = self.contains(direction);
+        self.0 &= !Self::bit(direction);
+        removed
+    }
+
+    pub fn contains(&self, direction: Direction) -> bool {
+        self.0 & Self::bit(direction) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: DirectionSet) -> DirectionSet {
+        DirectionSet(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: DirectionSet) -> DirectionSet {
+        DirectionSet(self.0 & other.0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Direction> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter(|&direction| self.contains(direction))
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> Self {
+        let mut set = DirectionSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Direction> for DirectionSet {
+    fn extend<I: IntoIterator<Item = Direction>>(&mut self, iter: I) {
+        for direction in iter {
+            self.insert(direction);
+        }
+    }
+}
+
+impl<T> From<Direction> for Coordinates<T>
 where
     T: Integer + Copy + Signed,
 {
-    fn into(self) -> Coordinates<T> {
-        match self {
+    fn from(direction: Direction) -> Self {
+        match direction {
             Direction::Up => Coordinates::origin().up(),
             Direction::Down => Coordinates::origin().down(),
             Direction::Left => Coordinates::origin().left(),
@@ -77,3 +242,230 @@ where
         }
     }
 }
+
+/// The eight directions of a compass, on screen: north is up.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// Clockwise from north.
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Reads `N`, `ne`, `south-west`, `NorthWest`... and the notations of [`Direction::parse`].
+    pub fn parse(notation: &str) -> Option<Compass> {
+        let name: String = notation
+            .chars()
+            .filter(|c| c.is_ascii_alphabetic())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        match name.as_str() {
+            "ne" | "northeast" => Some(Compass::NorthEast),
+            "se" | "southeast" => Some(Compass::SouthEast),
+            "sw" | "southwest" => Some(Compass::SouthWest),
+            "nw" | "northwest" => Some(Compass::NorthWest),
+            _ => Direction::parse(notation).map(Compass::from),
+        }
+    }
+
+    /// A 45° turn.
+    pub fn turn_right(&self) -> Compass {
+        Compass::ALL[(self.index() + 1) % 8]
+    }
+
+    /// A 45° turn.
+    pub fn turn_left(&self) -> Compass {
+        Compass::ALL[(self.index() + 7) % 8]
+    }
+
+    pub fn reverse_direction(&self) -> Compass {
+        Compass::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    /// The orthogonal moves adding up to this direction, vertical first.
+    pub fn directions(&self) -> impl Iterator<Item = Direction> {
+        let vertical = match self {
+            Compass::North | Compass::NorthEast | Compass::NorthWest => Some(Direction::Up),
+            Compass::South | Compass::SouthEast | Compass::SouthWest => Some(Direction::Down),
+            Compass::East | Compass::West => None,
+        };
+        let horizontal = match self {
+            Compass::East | Compass::NorthEast | Compass::SouthEast => Some(Direction::Right),
+            Compass::West | Compass::NorthWest | Compass::SouthWest => Some(Direction::Left),
+            Compass::North | Compass::South => None,
+        };
+        vertical.into_iter().chain(horizontal)
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+            Direction::Right => Compass::East,
+        }
+    }
+}
+
+impl Display for Compass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Compass::North => "N",
+            Compass::NorthEast => "NE",
+            Compass::East => "E",
+            Compass::SouthEast => "SE",
+            Compass::South => "S",
+            Compass::SouthWest => "SW",
+            Compass::West => "W",
+            Compass::NorthWest => "NW",
+        };
+        write!(f, "{s}")
+    }
+}
+
+impl<T> From<Compass> for Coordinates<T>
+where
+    T: Integer + Copy + Signed,
+{
+    fn from(compass: Compass) -> Self {
+        Coordinates::origin().step_compass(compass)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (notations, direction) in [
+            (["U", "N", "^", "up"], Direction::Up),
+            (["D", "s", "v", "South"], Direction::Down),
+            (["L", "W", "<", "left"], Direction::Left),
+            (["r", "E", ">", "east"], Direction::Right),
+        ] {
+            for notation in notations {
+                assert_eq!(Direction::parse(notation), Some(direction), "{notation}");
+            }
+        }
+        assert_eq!(Direction::parse("x"), None);
+        assert_eq!(Direction::parse("ne"), None);
+
+        assert_eq!(Compass::parse("ne"), Some(Compass::NorthEast));
+        assert_eq!(Compass::parse("south-west"), Some(Compass::SouthWest));
+        assert_eq!(Compass::parse("NW"), Some(Compass::NorthWest));
+        assert_eq!(Compass::parse("^"), Some(Compass::North));
+        assert_eq!(Compass::parse("nn"), None);
+        for compass in Compass::ALL {
+            assert_eq!(Compass::parse(&compass.to_string()), Some(compass));
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        for (i, direction) in Direction::ALL.into_iter().enumerate() {
+            assert_eq!(direction.index(), i);
+            assert_eq!(Direction::try_from(i as u8), Ok(direction));
+            assert_eq!(u8::from(direction), i as u8);
+        }
+        assert_eq!(Direction::try_from(5u8), Err(DirectionIndexError(5)));
+
+        assert_eq!("U".parse(), Ok(Direction::Up));
+        assert_eq!("west".parse(), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('>'), Ok(Direction::Right));
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert!("?".parse::<Direction>().is_err());
+        assert!(Direction::try_from('#').is_err());
+    }
+
+    #[test]
+    fn test_direction_set() {
+        let mut set = DirectionSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Direction::Up));
+        assert!(!set.insert(Direction::Up));
+        assert!(set.insert(Direction::Left));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(Direction::Left));
+        assert!(!set.contains(Direction::Right));
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            vec![Direction::Up, Direction::Left]
+        );
+
+        assert!(set.remove(Direction::Up));
+        assert!(!set.remove(Direction::Up));
+        assert_eq!(set, DirectionSet::from_iter([Direction::Left]));
+
+        let all: DirectionSet = Direction::ALL.into_iter().collect();
+        assert_eq!(all, DirectionSet::ALL);
+        assert_eq!(all.intersection(set), set);
+        assert_eq!(
+            set.union(DirectionSet::from_iter([Direction::Right])).len(),
+            2
+        );
+        assert_eq!(std::mem::size_of::<DirectionSet>(), 1);
+    }
+
+    #[test]
+    fn test_compass() {
+        assert_eq!(Compass::North.turn_right(), Compass::NorthEast);
+        assert_eq!(Compass::North.turn_left(), Compass::NorthWest);
+        assert_eq!(Compass::SouthWest.reverse_direction(), Compass::NorthEast);
+        assert!(Compass::SouthEast.is_diagonal());
+        assert!(!Compass::West.is_diagonal());
+
+        assert_eq!(
+            Coordinates::<i32>::from(Compass::NorthEast),
+            Coordinates::new(1, -1)
+        );
+        assert_eq!(
+            Coordinates::<i32>::from(Compass::SouthWest),
+            Coordinates::new(-1, 1)
+        );
+        for direction in Direction::ALL {
+            assert_eq!(
+                Coordinates::<i32>::from(Compass::from(direction)),
+                Coordinates::from(direction)
+            );
+            assert_eq!(
+                Compass::from(direction).turn_right().turn_right(),
+                Compass::from(direction.turn_right())
+            );
+        }
+
+        let coord: Coordinates<usize> = Coordinates::new(0, 1);
+        assert_eq!(
+            coord.try_step_compass(Compass::NorthEast),
+            Some(Coordinates::new(1, 0))
+        );
+        assert_eq!(coord.try_step_compass(Compass::SouthWest), None);
+    }
+}
@@ -0,0 +1,280 @@
+//! Disjoint sets (union-find) with path compression and union by size, so that merging and
+//! looking up components is near constant time.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// Disjoint sets over the indices `0..len`.
+#[derive(Debug, Clone, Default)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl DisjointSet {
+    /// `len` singletons.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            count: len,
+        }
+    }
+
+    /// Adds a singleton and returns its index.
+    pub fn add(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.sizes.push(1);
+        self.count += 1;
+        index
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of components.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The representative of the component of `index`.
+    pub fn find(&mut self, index: usize) -> usize {
+        let mut root = index;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = index;
+        while self.parents[current] != root {
+            current = std::mem::replace(&mut self.parents[current], root);
+        }
+        root
+    }
+
+    /// Merges the components of `a` and `b`, returning false if they already were the same.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (large, small) = if self.sizes[a] < self.sizes[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+        self.count -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the component of `index`.
+    pub fn size(&mut self, index: usize) -> usize {
+        let root = self.find(index);
+        self.sizes[root]
+    }
+
+    /// Size of every component, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&index| self.parents[index] == index)
+            .map(|root| self.sizes[root])
+            .collect()
+    }
+
+    /// The indices of every component, in increasing order within each component and ordered
+    /// by their smallest index.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut positions = HashMap::new();
+        let mut components: Vec<Vec<usize>> = Vec::new();
+        for index in 0..self.len() {
+            let root = self.find(index);
+            let position = *positions.entry(root).or_insert_with(|| {
+                components.push(Vec::new());
+                components.len() - 1
+            });
+            components[position].push(index);
+        }
+        components
+    }
+}
+
+/// Disjoint sets over arbitrary values, added on first use.
+#[derive(Debug, Clone)]
+pub struct KeyedDisjointSet<K> {
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+    set: DisjointSet,
+}
+
+impl<K> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        Self {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            set: DisjointSet::default(),
+        }
+    }
+}
+
+impl<K> KeyedDisjointSet<K>
+where
+    K: Hash + Eq + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `key` as a singleton unless it is already there, and returns its index.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&index) = self.indices.get(&key) {
+            return index;
+        }
+        let index = self.set.add();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key);
+        index
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn count(&self) -> usize {
+        self.set.count()
+    }
+
+    /// The representative of the component of `key`, if it was added.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.indices.get(key)?;
+        let root = self.set.find(index);
+        Some(&self.keys[root])
+    }
+
+    /// Merges the components of `a` and `b`, adding them first if needed. Returns false if
+    /// they already were the same.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.set.union(a, b)
+    }
+
+    /// False when either one wasn't added.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.set.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Size of the component of `key`, 0 if it wasn't added.
+    pub fn size(&mut self, key: &K) -> usize {
+        self.indices
+            .get(key)
+            .copied()
+            .map_or(0, |index| self.set.size(index))
+    }
+
+    /// Size of every component, in no particular order.
+    pub fn component_sizes(&self) -> Vec<usize> {
+        self.set.component_sizes()
+    }
+
+    /// The keys of every component, in insertion order.
+    pub fn components(&mut self) -> Vec<Vec<K>> {
+        self.set
+            .components()
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| self.keys[index].clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl<K> FromIterator<K> for KeyedDisjointSet<K>
+where
+    K: Hash + Eq + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for key in iter {
+            set.insert(key);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disjoint_set() {
+        let mut set = DisjointSet::new(6);
+        assert_eq!(set.count(), 6);
+        assert!(set.union(0, 1));
+        assert!(set.union(2, 3));
+        assert!(set.union(1, 3));
+        assert!(!set.union(0, 2));
+        assert_eq!(set.count(), 3);
+        assert!(set.connected(0, 3));
+        assert!(!set.connected(0, 4));
+        assert_eq!(set.size(2), 4);
+        assert_eq!(set.size(5), 1);
+
+        let mut sizes = set.component_sizes();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 1, 4]);
+        assert_eq!(set.components(), vec![vec![0, 1, 2, 3], vec![4], vec![5]]);
+
+        let index = set.add();
+        assert_eq!(index, 6);
+        assert!(set.union(5, 6));
+        assert_eq!(set.count(), 3);
+        assert_eq!(set.len(), 7);
+    }
+
+    #[test]
+    fn test_keyed_disjoint_set() {
+        let mut set: KeyedDisjointSet<&str> = ["a", "b"].into_iter().collect();
+        assert_eq!(set.count(), 2);
+        assert!(set.union("a", "c"));
+        assert!(set.union("d", "e"));
+        assert!(!set.union("c", "a"));
+        assert_eq!(set.len(), 5);
+        assert_eq!(set.count(), 3);
+        assert!(set.connected(&"a", &"c"));
+        assert!(!set.connected(&"a", &"z"));
+        assert_eq!(set.size(&"c"), 2);
+        assert_eq!(set.size(&"z"), 0);
+        assert_eq!(set.find(&"z"), None);
+        assert_eq!(set.find(&"c").copied(), set.find(&"a").copied());
+        assert_eq!(
+            set.components(),
+            vec![vec!["a", "c"], vec!["b"], vec!["d", "e"]]
+        );
+    }
+}
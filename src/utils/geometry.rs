@@ -0,0 +1,287 @@
+//! Polygons on the integer lattice, given as their vertices in order, either way around. The
+//! last vertex connects back to the first one. Everything is computed exactly with integers.
+
+use num::{Integer, Signed};
+
+use super::coords::Coordinates;
+
+/// Pairs of consecutive vertices, the last one paired with the first.
+fn edges<T>(
+    vertices: &[Coordinates<T>],
+) -> impl Iterator<Item = (Coordinates<T>, Coordinates<T>)> + '_
+where
+    T: Integer + Copy,
+{
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Twice the area of the polygon with the shoelace formula, positive when the vertices go
+/// clockwise on screen.
+pub fn twice_signed_area<T>(vertices: &[Coordinates<T>]) -> T
+where
+    T: Integer + Copy,
+{
+    edges(vertices).fold(T::zero(), |acc, (a, b)| acc + a.cross(b))
+}
+
+/// Rounded down when the area isn't whole, which can't happen for rectilinear polygons.
+pub fn area<T>(vertices: &[Coordinates<T>]) -> T
+where
+    T: Integer + Signed + Copy,
+{
+    twice_signed_area(vertices).abs() / (T::one() + T::one())
+}
+
+/// Number of lattice points on the edges of the polygon.
+pub fn boundary_points<T>(vertices: &[Coordinates<T>]) -> T
+where
+    T: Integer + Signed + Copy,
+{
+    edges(vertices).fold(T::zero(), |acc, (a, b)| {
+        let delta = b - a;
+        acc + delta.x().abs().gcd(&delta.y().abs())
+    })
+}
+
+/// Number of lattice points strictly inside the polygon, with Pick's theorem.
+pub fn interior_points<T>(vertices: &[Coordinates<T>]) -> T
+where
+    T: Integer + Signed + Copy,
+{
+    let two = T::one() + T::one();
+    (twice_signed_area(vertices).abs() - boundary_points(vertices) + two) / two
+}
+
+/// Number of lattice points inside the polygon or on its edges, which is the number of cells
+/// of a loop dug through the centres of grid cells.
+pub fn lattice_points<T>(vertices: &[Coordinates<T>]) -> T
+where
+    T: Integer + Signed + Copy,
+{
+    interior_points(vertices) + boundary_points(vertices)
+}
+
+/// Whether `point` lies on the segment from `a` to `b`, ends included.
+pub fn on_segment<T>(point: Coordinates<T>, a: Coordinates<T>, b: Coordinates<T>) -> bool
+where
+    T: Integer + Copy,
+{
+    (b - a).cross(point - a).is_zero()
+        && a.x().min(b.x()) <= point.x()
+        && point.x() <= a.x().max(b.x())
+        && a.y().min(b.y()) <= point.y()
+        && point.y() <= a.y().max(b.y())
+}
+
+pub fn on_boundary<T>(vertices: &[Coordinates<T>], point: Coordinates<T>) -> bool
+where
+    T: Integer + Copy,
+{
+    edges(vertices).any(|(a, b)| on_segment(point, a, b))
+}
+
+/// Whether `point` is inside the polygon or on its boundary.
+pub fn contains<T>(vertices: &[Coordinates<T>], point: Coordinates<T>) -> bool
+where
+    T: Integer + Copy,
+{
+    if on_boundary(vertices, point) {
+        return true;
+    }
+
+    // Cast a ray toward growing x and count the edges it crosses.
+    edges(vertices)
+        .filter(|&(a, b)| (a.y() > point.y()) != (b.y() > point.y()))
+        .filter(|&(a, b)| ((b - a).cross(point - a) > T::zero()) == (b.y() > a.y()))
+        .count()
+        % 2
+        == 1
+}
+
+/// Whether the segments `a1`-`a2` and `b1`-`b2` have at least one point in common, touching
+/// included.
+pub fn segments_intersect<T>(
+    a1: Coordinates<T>,
+    a2: Coordinates<T>,
+    b1: Coordinates<T>,
+    b2: Coordinates<T>,
+) -> bool
+where
+    T: Integer + Copy,
+{
+    let side = |from: Coordinates<T>, to: Coordinates<T>, point: Coordinates<T>| {
+        (to - from).cross(point - from).cmp(&T::zero())
+    };
+    let (d1, d2) = (side(b1, b2, a1), side(b1, b2, a2));
+    let (d3, d4) = (side(a1, a2, b1), side(a1, a2, b2));
+
+    (d1 != d2 && d3 != d4 && d1.is_ne() && d2.is_ne() && d3.is_ne() && d4.is_ne())
+        || on_segment(a1, b1, b2)
+        || on_segment(a2, b1, b2)
+        || on_segment(b1, a1, a2)
+        || on_segment(b2, a1, a2)
+}
+
+/// Whether the axis-aligned rectangle with opposite corners `c1` and `c2` lies inside the
+/// rectilinear polygon, edges included.
+pub fn rectangle_inside<T>(
+    vertices: &[Coordinates<T>],
+    c1: Coordinates<T>,
+    c2: Coordinates<T>,
+) -> bool
+where
+    T: Integer + Copy,
+{
+    let (min_x, max_x) = (c1.x().min(c2.x()), c1.x().max(c2.x()));
+    let (min_y, max_y) = (c1.y().min(c2.y()), c1.y().max(c2.y()));
+    let corners = [
+        c1,
+        c2,
+        Coordinates::new(c1.x(), c2.y()),
+        Coordinates::new(c2.x(), c1.y()),
+    ];
+    if !corners.iter().all(|&corner| contains(vertices, corner)) {
+        return false;
+    }
+
+    // An edge going through the inside of the rectangle leaves part of it outside.
+    let crosses = |lo: T, hi: T, a: T, b: T| {
+        let (a, b) = (a.min(b), a.max(b));
+        if lo == hi {
+            a < lo && lo < b
+        } else {
+            a < hi && lo < b
+        }
+    };
+    if edges(vertices)
+        .any(|(a, b)| crosses(min_x, max_x, a.x(), b.x()) && crosses(min_y, max_y, a.y(), b.y()))
+    {
+        return false;
+    }
+
+    // Without any edge inside, the rectangle is entirely in or entirely out, and its centre
+    // tells which. Doubling everything keeps the centre on the lattice.
+    let doubled: Vec<_> = vertices.iter().map(|&vertex| vertex + vertex).collect();
+    contains(&doubled, c1 + c2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The dig plan of 2023 day 18.
+    fn lagoon() -> Vec<Coordinates<i64>> {
+        [
+            (0, 0),
+            (6, 0),
+            (6, 5),
+            (4, 5),
+            (4, 7),
+            (6, 7),
+            (6, 9),
+            (1, 9),
+            (1, 7),
+            (0, 7),
+            (0, 5),
+            (2, 5),
+            (2, 2),
+            (0, 2),
+        ]
+        .into_iter()
+        .map(|(x, y)| Coordinates::new(x, y))
+        .collect()
+    }
+
+    /// The red tiles of 2025 day 9.
+    fn tiles() -> Vec<Coordinates<i64>> {
+        [
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ]
+        .into_iter()
+        .map(|(x, y)| Coordinates::new(x, y))
+        .collect()
+    }
+
+    #[test]
+    fn test_area() {
+        let lagoon = lagoon();
+        assert_eq!(twice_signed_area(&lagoon), 84);
+        assert_eq!(area(&lagoon), 42);
+        assert_eq!(boundary_points(&lagoon), 38);
+        assert_eq!(interior_points(&lagoon), 24);
+        assert_eq!(lattice_points(&lagoon), 62);
+
+        let reversed: Vec<_> = lagoon.iter().rev().copied().collect();
+        assert_eq!(twice_signed_area(&reversed), -84);
+        assert_eq!(lattice_points(&reversed), 62);
+
+        let triangle = [
+            Coordinates::new(0, 0),
+            Coordinates::new(4, 0),
+            Coordinates::new(0, 3),
+        ];
+        assert_eq!(area(&triangle), 6);
+        assert_eq!(boundary_points(&triangle), 8);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+
+    #[test]
+    fn test_contains() {
+        let tiles = tiles();
+        assert!(contains(&tiles, Coordinates::new(7, 1)));
+        assert!(contains(&tiles, Coordinates::new(9, 1)));
+        assert!(contains(&tiles, Coordinates::new(5, 4)));
+        assert!(contains(&tiles, Coordinates::new(10, 6)));
+        assert!(!contains(&tiles, Coordinates::new(5, 2)));
+        assert!(!contains(&tiles, Coordinates::new(5, 6)));
+        assert!(!contains(&tiles, Coordinates::new(12, 4)));
+        assert!(on_boundary(&tiles, Coordinates::new(11, 4)));
+        assert!(!on_boundary(&tiles, Coordinates::new(10, 4)));
+    }
+
+    #[test]
+    fn test_segments_intersect() {
+        let p = |x, y| Coordinates::new(x, y);
+        assert!(segments_intersect(p(0, 0), p(4, 4), p(0, 4), p(4, 0)));
+        assert!(segments_intersect(p(0, 0), p(4, 0), p(4, 0), p(4, 4)));
+        assert!(segments_intersect(p(0, 0), p(4, 0), p(2, 0), p(6, 0)));
+        assert!(!segments_intersect(p(0, 0), p(4, 0), p(5, 0), p(6, 0)));
+        assert!(!segments_intersect(p(0, 0), p(4, 4), p(1, 0), p(5, 4)));
+        assert!(!segments_intersect(p(0, 0), p(1, 1), p(3, 0), p(2, 1)));
+    }
+
+    #[test]
+    fn test_rectangle_inside() {
+        let tiles = tiles();
+        let p = |x, y| Coordinates::new(x, y);
+        assert!(rectangle_inside(&tiles, p(9, 5), p(2, 3)));
+        assert!(rectangle_inside(&tiles, p(7, 3), p(11, 1)));
+        assert!(rectangle_inside(&tiles, p(9, 7), p(9, 5)));
+        assert!(!rectangle_inside(&tiles, p(2, 5), p(11, 1)));
+        assert!(!rectangle_inside(&tiles, p(2, 3), p(11, 7)));
+
+        // A U whose notch is bounded by the rectangle's corners.
+        let u = [
+            p(0, 0),
+            p(1, 0),
+            p(1, 2),
+            p(2, 2),
+            p(2, 0),
+            p(3, 0),
+            p(3, 3),
+            p(0, 3),
+        ];
+        assert!(!rectangle_inside(&u, p(1, 0), p(2, 2)));
+        assert!(rectangle_inside(&u, p(0, 2), p(3, 3)));
+    }
+}
@@ -0,0 +1,502 @@
+//! Directed graphs over any hashable node ids, such as puzzle names or numbers, for ordering,
+//! reachability and path counting puzzles.
+
+use std::{
+    borrow::Borrow,
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{Display, Formatter},
+    hash::Hash,
+};
+
+use itertools::Itertools;
+
+/// A directed graph. Nodes are stored in insertion order, which every traversal follows so
+/// that results are deterministic.
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<usize>>,
+}
+
+/// The graph has a cycle, going through these nodes in order and back to the first one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError<N>(pub Vec<N>);
+
+impl<N> Display for CycleError<N>
+where
+    N: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "cycle through {}", self.0.iter().join(" -> "))
+    }
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N> Graph<N>
+where
+    N: Hash + Eq + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the graph from each node and the nodes it points to.
+    pub fn from_adjacency<A, I>(adjacency: A) -> Self
+    where
+        A: IntoIterator<Item = (N, I)>,
+        I: IntoIterator<Item = N>,
+    {
+        let mut graph = Self::new();
+        for (from, successors) in adjacency {
+            graph.add_node(from.clone());
+            for to in successors {
+                graph.add_edge(from.clone(), to);
+            }
+        }
+        graph
+    }
+
+    /// Adds `node` unless it is already there, and returns its index.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        index
+    }
+
+    /// Adds an edge, and its nodes if needed. Parallel edges are kept.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let (from, to) = (self.add_node(from), self.add_node(to));
+        self.edges[from].push(to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.edges.iter().enumerate().flat_map(move |(from, tos)| {
+            tos.iter()
+                .map(move |&to| (&self.nodes[from], &self.nodes[to]))
+        })
+    }
+
+    fn index<Q>(&self, node: &Q) -> Option<usize>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(node).copied()
+    }
+
+    pub fn contains<Q>(&self, node: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.contains_key(node)
+    }
+
+    /// The nodes `node` points to, none if it isn't in the graph.
+    pub fn successors<Q>(&self, node: &Q) -> impl Iterator<Item = &N>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.index(node)
+            .map_or(&[][..], |index| &self.edges[index])
+            .iter()
+            .map(|&to| &self.nodes[to])
+    }
+
+    /// The same graph with every edge pointing the other way.
+    pub fn reversed(&self) -> Self {
+        let mut edges = vec![Vec::new(); self.len()];
+        for (from, tos) in self.edges.iter().enumerate() {
+            for &to in tos {
+                edges[to].push(from);
+            }
+        }
+        Self {
+            nodes: self.nodes.clone(),
+            indices: self.indices.clone(),
+            edges,
+        }
+    }
+
+    /// The subgraph made of `nodes` and the edges between them.
+    pub fn subgraph<'a>(&self, nodes: impl IntoIterator<Item = &'a N>) -> Self
+    where
+        N: 'a,
+    {
+        let mut graph = Self::new();
+        for node in nodes {
+            graph.add_node(node.clone());
+        }
+        for (from, to) in self.edges() {
+            if graph.contains(from) && graph.contains(to) {
+                graph.add_edge(from.clone(), to.clone());
+            }
+        }
+        graph
+    }
+
+    /// Marks the nodes reachable from `start`.
+    fn reach(&self, start: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(index) = stack.pop() {
+            for &next in &self.edges[index] {
+                if !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Every node reachable from `start`, itself included.
+    pub fn reachable<Q>(&self, start: &Q) -> HashSet<N>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(start) = self.index(start) else {
+            return HashSet::new();
+        };
+        self.reach(start)
+            .iter()
+            .positions(|&seen| seen)
+            .map(|index| self.nodes[index].clone())
+            .collect()
+    }
+
+    /// Kahn's algorithm over the `included` nodes, as indices.
+    fn topological_order(&self, included: &[bool]) -> Result<Vec<usize>, CycleError<N>> {
+        let mut in_degrees = vec![0; self.len()];
+        for (from, tos) in self.edges.iter().enumerate() {
+            for &to in tos {
+                if included[from] && included[to] {
+                    in_degrees[to] += 1;
+                }
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.len())
+            .filter(|&index| included[index] && in_degrees[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(index) = queue.pop_front() {
+            order.push(index);
+            for &next in self.edges[index].iter().filter(|&&next| included[next]) {
+                in_degrees[next] -= 1;
+                if in_degrees[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        if order.len() == included.iter().filter(|&&included| included).count() {
+            return Ok(order);
+        }
+
+        // Every node left has a predecessor left, so walking backwards must loop.
+        let reversed = self.reversed();
+        let left = |index: usize| included[index] && in_degrees[index] > 0;
+        let mut position = HashMap::new();
+        let mut walk = Vec::new();
+        let mut index = (0..self.len()).find(|&index| left(index)).unwrap();
+        while !position.contains_key(&index) {
+            position.insert(index, walk.len());
+            walk.push(index);
+            index = *reversed.edges[index]
+                .iter()
+                .find(|&&previous| left(previous))
+                .unwrap();
+        }
+        let cycle = walk[position[&index]..]
+            .iter()
+            .rev()
+            .map(|&index| self.nodes[index].clone())
+            .collect();
+        Err(CycleError(cycle))
+    }
+
+    /// Orders the nodes so that every edge goes forward, preferring insertion order among
+    /// nodes that are free to go, or reports a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<N>, CycleError<N>> {
+        Ok(self
+            .topological_order(&vec![true; self.len()])?
+            .into_iter()
+            .map(|index| self.nodes[index].clone())
+            .collect())
+    }
+
+    /// Tarjan's algorithm without recursion. Components come out in reverse topological
+    /// order, each one after every component it points to.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<N>> {
+        const UNVISITED: usize = usize::MAX;
+
+        let mut order = vec![UNVISITED; self.len()];
+        let mut low = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = Vec::new();
+        let mut components = Vec::new();
+        let mut counter = 0;
+
+        for root in 0..self.len() {
+            if order[root] != UNVISITED {
+                continue;
+            }
+            // Nodes being explored, with how many of their edges were followed.
+            let mut calls = vec![(root, 0)];
+            while let Some(&mut (index, ref mut edge)) = calls.last_mut() {
+                if *edge == 0 && order[index] == UNVISITED {
+                    order[index] = counter;
+                    low[index] = counter;
+                    counter += 1;
+                    stack.push(index);
+                    on_stack[index] = true;
+                }
+
+                if let Some(&next) = self.edges[index].get(*edge) {
+                    *edge += 1;
+                    if order[next] == UNVISITED {
+                        calls.push((next, 0));
+                    } else if on_stack[next] {
+                        low[index] = low[index].min(order[next]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    low[parent] = low[parent].min(low[index]);
+                }
+                if low[index] == order[index] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(self.nodes[member].clone());
+                        if member == index {
+                            break;
+                        }
+                    }
+                    component.reverse();
+                    components.push(component);
+                }
+            }
+        }
+        components
+    }
+
+    /// Number of distinct paths from `from` to `to`. Panics if a cycle can be reached from
+    /// `from`, as there would be infinitely many.
+    pub fn count_paths<Q>(&self, from: &Q, to: &Q) -> usize
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (Some(from), Some(to)) = (self.index(from), self.index(to)) else {
+            return 0;
+        };
+        let order = match self.topological_order(&self.reach(from)) {
+            Ok(order) => order,
+            Err(CycleError(cycle)) => {
+                panic!(
+                    "paths can't be counted with a cycle of {} nodes",
+                    cycle.len()
+                )
+            }
+        };
+
+        let mut paths = vec![0; self.len()];
+        paths[from] = 1;
+        for index in order {
+            for &next in &self.edges[index] {
+                paths[next] += paths[index];
+            }
+        }
+        paths[to]
+    }
+
+    /// Number of distinct paths from `from` to `to` going through every waypoint, in any
+    /// order.
+    pub fn count_paths_through<Q>(&self, from: &Q, to: &Q, waypoints: &[&Q]) -> usize
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        waypoints
+            .iter()
+            .permutations(waypoints.len())
+            .map(|order| {
+                std::iter::once(&from)
+                    .chain(order)
+                    .chain(std::iter::once(&to))
+                    .tuple_windows()
+                    .map(|(a, b)| self.count_paths(*a, *b))
+                    .product::<usize>()
+            })
+            .sum()
+    }
+}
+
+impl<N> Graph<N>
+where
+    N: Hash + Eq + Clone + Display,
+{
+    /// The graph in Graphviz's DOT language, to render with `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n");
+        for node in &self.nodes {
+            dot.push_str(&format!("    \"{node}\";\n"));
+        }
+        for (from, to) in self.edges() {
+            dot.push_str(&format!("    \"{from}\" -> \"{to}\";\n"));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<N> FromIterator<(N, N)> for Graph<N>
+where
+    N: Hash + Eq + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        let mut graph = Self::new();
+        for (from, to) in iter {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The devices of 2025 day 11, part two.
+    fn devices() -> Graph<&'static str> {
+        let input = "svr: aaa bbb
+aaa: fft
+fft: ccc
+bbb: tty
+tty: ccc
+ccc: ddd eee
+ddd: hub
+hub: fff
+eee: dac
+dac: fff
+fff: ggg hhh
+ggg: out
+hhh: out";
+        Graph::from_adjacency(input.lines().map(|line| {
+            let (from, to) = line.split_once(": ").unwrap();
+            (from, to.split(' ').collect::<Vec<_>>())
+        }))
+    }
+
+    #[test]
+    fn test_build() {
+        let graph = devices();
+        assert_eq!(graph.len(), 14);
+        assert_eq!(graph.edge_count(), 16);
+        assert_eq!(
+            graph.successors("ccc").collect::<Vec<_>>(),
+            [&"ddd", &"eee"]
+        );
+        assert_eq!(graph.successors("out").count(), 0);
+        assert_eq!(graph.successors("nope").count(), 0);
+        assert_eq!(
+            graph.reversed().successors("fff").collect::<Vec<_>>(),
+            [&"hub", &"dac"]
+        );
+
+        let numbers: Graph<u32> = [(1, 2), (2, 3)].into_iter().collect();
+        assert_eq!(
+            numbers.to_dot(),
+            "digraph {\n    \"1\";\n    \"2\";\n    \"3\";\n    \"1\" -> \"2\";\n    \"2\" -> \"3\";\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let rules: Graph<u32> = [(97, 75), (75, 47), (97, 47), (47, 61), (61, 53), (75, 53)]
+            .into_iter()
+            .collect();
+        assert_eq!(rules.topological_sort(), Ok(vec![97, 75, 47, 61, 53]));
+
+        let mut looped = rules.clone();
+        looped.add_edge(53, 75);
+        let Err(CycleError(cycle)) = looped.topological_sort() else {
+            panic!("the cycle should be found");
+        };
+        assert!(cycle.len() >= 2);
+        for (a, b) in cycle.iter().circular_tuple_windows() {
+            assert!(looped.successors(a).any(|next| next == b));
+        }
+    }
+
+    #[test]
+    fn test_components() {
+        let graph: Graph<char> = [
+            ('a', 'b'),
+            ('b', 'c'),
+            ('c', 'a'),
+            ('c', 'd'),
+            ('d', 'e'),
+            ('e', 'd'),
+            ('f', 'e'),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            graph.strongly_connected_components(),
+            vec![vec!['d', 'e'], vec!['a', 'b', 'c'], vec!['f']]
+        );
+        assert_eq!(graph.reachable(&'d'), HashSet::from(['d', 'e']));
+        assert_eq!(graph.reachable(&'a').len(), 5);
+        assert!(graph.reachable(&'z').is_empty());
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let graph = devices();
+        assert_eq!(graph.count_paths("svr", "out"), 8);
+        assert_eq!(graph.count_paths("out", "svr"), 0);
+        assert_eq!(graph.count_paths("svr", "svr"), 1);
+        assert_eq!(graph.count_paths_through("svr", "out", &["fft", "dac"]), 2);
+        assert_eq!(graph.count_paths_through("svr", "out", &["dac", "fft"]), 2);
+        assert_eq!(graph.count_paths_through("svr", "out", &[]), 8);
+    }
+}
@@ -0,0 +1,801 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use num::Num;
+
+use super::{coords::Coordinates, direction::Direction};
+
+/// Offsets of the 4 cells sharing a side, clockwise from the one above.
+pub const ORTHOGONAL: &[(i64, i64)] = &[(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// Offsets of the 4 cells sharing only a corner, clockwise from the top right one.
+pub const DIAGONAL: &[(i64, i64)] = &[(1, -1), (1, 1), (-1, 1), (-1, -1)];
+/// Offsets of the 8 surrounding cells, clockwise from the one above.
+pub const ADJACENT: &[(i64, i64)] = &[
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// What happens to neighbours that fall outside of the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edges {
+    /// They are skipped.
+    Bounded,
+    /// They wrap around to the other side, as if the grid was a torus.
+    Wrapping,
+    /// They are kept, for grids that tile an infinite plane. Only coordinates that don't fit
+    /// in the coordinate type, like negative `usize`, are skipped.
+    Unbounded,
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+///
+/// Cells are addressed with `Coordinates` where `x` is the column and `y` the row, `(0, 0)`
+/// being the top left corner. Coordinates of any integer type are accepted; negative or out of
+/// range ones are simply outside the grid.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Builds a grid from text, one row per line, mapping every character with `f`.
+    ///
+    /// Panics if the lines don't all have the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let line_width = cells.len() - before;
+            match width {
+                None => width = Some(line_width),
+                Some(width) => assert_eq!(width, line_width, "row {height} has a different width"),
+            }
+            height += 1;
+        }
+
+        Self::new(width.unwrap_or(0), height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of<N>(&self, coord: Coordinates<N>) -> Option<usize>
+    where
+        N: Num + Copy + TryInto<usize>,
+    {
+        let x: usize = coord.x().try_into().ok()?;
+        let y: usize = coord.y().try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    fn coordinates_of(&self, index: usize) -> Coordinates<usize> {
+        Coordinates::new(index % self.width, index / self.width)
+    }
+
+    pub fn contains<N>(&self, coord: Coordinates<N>) -> bool
+    where
+        N: Num + Copy + TryInto<usize>,
+    {
+        self.index_of(coord).is_some()
+    }
+
+    pub fn get<N>(&self, coord: Coordinates<N>) -> Option<&T>
+    where
+        N: Num + Copy + TryInto<usize>,
+    {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut<N>(&mut self, coord: Coordinates<N>) -> Option<&mut T>
+    where
+        N: Num + Copy + TryInto<usize>,
+    {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks` panics on a zero size, an empty grid just has no rows.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.cells.chunks_mut(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell with its coordinates, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (Coordinates<usize>, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coordinates_of(i), cell))
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = (Coordinates<usize>, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (Coordinates::new(i % width, i / width), cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Coordinates of the first cell, row by row, matching `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Coordinates<usize>> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.coordinates_of(i))
+    }
+
+    /// The cell of the grid that `coord` lands on when the grid tiles the whole plane, `None`
+    /// for an empty grid which has nothing to tile it with.
+    pub fn wrap<N>(&self, coord: Coordinates<N>) -> Option<Coordinates<usize>>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        if self.width == 0 || self.height == 0 {
+            return None;
+        }
+        let (x, y) = to_i64(coord).expect("coordinates should fit in an i64");
+        Some(Coordinates::new(
+            x.rem_euclid(self.width as i64) as usize,
+            y.rem_euclid(self.height as i64) as usize,
+        ))
+    }
+
+    /// Same as `get` for a grid tiling the whole plane, any coordinates have a cell unless the
+    /// grid is empty.
+    pub fn get_wrapping<N>(&self, coord: Coordinates<N>) -> Option<&T>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        self.wrap(coord).map(|coord| &self[coord])
+    }
+
+    /// The cells at `offsets` from `coord`, see `ORTHOGONAL`, `DIAGONAL` and `ADJACENT`.
+    pub fn neighbors<'a, N>(
+        &'a self,
+        coord: Coordinates<N>,
+        offsets: &'a [(i64, i64)],
+        edges: Edges,
+    ) -> impl Iterator<Item = Coordinates<N>> + 'a
+    where
+        N: Num + Copy + TryInto<i64> + TryFrom<i64> + 'a,
+    {
+        let origin = to_i64(coord);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = origin?;
+            let (x, y) = (x + dx, y + dy);
+            let (x, y) = match edges {
+                Edges::Bounded if !self.contains(Coordinates::new(x, y)) => return None,
+                Edges::Wrapping => {
+                    let wrapped = self.wrap(Coordinates::new(x, y))?;
+                    (wrapped.x() as i64, wrapped.y() as i64)
+                }
+                _ => (x, y),
+            };
+            Some(Coordinates::new(N::try_from(x).ok()?, N::try_from(y).ok()?))
+        })
+    }
+
+    /// The up to 4 cells of the grid sharing a side with `coord`.
+    pub fn orthogonal_neighbors<'a, N>(
+        &'a self,
+        coord: Coordinates<N>,
+    ) -> impl Iterator<Item = Coordinates<N>> + 'a
+    where
+        N: Num + Copy + TryInto<i64> + TryFrom<i64> + 'a,
+    {
+        self.neighbors(coord, ORTHOGONAL, Edges::Bounded)
+    }
+
+    /// The up to 8 cells of the grid around `coord`.
+    pub fn adjacent_neighbors<'a, N>(
+        &'a self,
+        coord: Coordinates<N>,
+    ) -> impl Iterator<Item = Coordinates<N>> + 'a
+    where
+        N: Num + Copy + TryInto<i64> + TryFrom<i64> + 'a,
+    {
+        self.neighbors(coord, ADJACENT, Edges::Bounded)
+    }
+
+    /// The cells met walking from `from` (excluded) in `direction` until the edge of the grid.
+    /// Stop it earlier with `take_while`, or use `cast` to find the first cell that blocks it.
+    pub fn ray<N>(
+        &self,
+        from: Coordinates<N>,
+        direction: Direction,
+    ) -> impl Iterator<Item = (Coordinates<usize>, &T)>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        let step: Coordinates<i64> = direction.into();
+        let mut current = to_i64(from);
+        std::iter::from_fn(move || {
+            let (x, y) = current?;
+            let next = Coordinates::new(x + step.x(), y + step.y());
+            let index = self.index_of(next);
+            current = index.map(|_| (next.x(), next.y()));
+            index.map(|i| (self.coordinates_of(i), &self.cells[i]))
+        })
+    }
+
+    /// The first cell of the ray from `from` in `direction` matching `stop`, or `None` if the
+    /// ray leaves the grid first.
+    pub fn cast<N>(
+        &self,
+        from: Coordinates<N>,
+        direction: Direction,
+        stop: impl Fn(&T) -> bool,
+    ) -> Option<(Coordinates<usize>, &T)>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        self.ray(from, direction).find(|(_, cell)| stop(cell))
+    }
+
+    /// A lazy view of the grid transformed by `transform`.
+    pub fn view(&self, transform: Transform) -> View<'_, T> {
+        let (width, height) = transform.size(self.width, self.height);
+        View {
+            grid: self,
+            transform,
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+
+    /// A view of the `width`x`height` rectangle with its top left corner at `(x, y)`.
+    ///
+    /// Panics if the rectangle doesn't fit in the grid.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> View<'_, T> {
+        self.view(Transform::Identity).crop(x, y, width, height)
+    }
+
+    /// A view of the grid repeated `times_x` times horizontally and `times_y` vertically.
+    pub fn tiled(&self, times_x: usize, times_y: usize) -> View<'_, T> {
+        self.view(Transform::Identity).tiled(times_x, times_y)
+    }
+
+    /// A new grid with the cells moved by `transform`.
+    pub fn transformed(&self, transform: Transform) -> Self
+    where
+        T: Clone,
+    {
+        self.view(transform).to_grid()
+    }
+
+    /// Rows become columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Transpose)
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::RotateClockwise)
+    }
+
+    pub fn rotate_180(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::Rotate180)
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::RotateCounterclockwise)
+    }
+
+    /// Mirrors the grid left to right, reversing every row.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::FlipHorizontal)
+    }
+
+    /// Mirrors the grid top to bottom, reversing every column.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.transformed(Transform::FlipVertical)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+/// The 8 ways of rotating and mirroring a grid onto itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    RotateClockwise,
+    Rotate180,
+    RotateCounterclockwise,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    /// Mirrors along the anti-diagonal, from the top right to the bottom left corner.
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::RotateClockwise,
+        Transform::Rotate180,
+        Transform::RotateCounterclockwise,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    /// Size of a `width`x`height` grid once transformed.
+    fn size(self, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Transform::Identity
+            | Transform::Rotate180
+            | Transform::FlipHorizontal
+            | Transform::FlipVertical => (width, height),
+            _ => (height, width),
+        }
+    }
+
+    /// Where the cell at `(x, y)` of the transformed grid comes from in the original
+    /// `width`x`height` one.
+    fn source(self, x: usize, y: usize, width: usize, height: usize) -> (usize, usize) {
+        match self {
+            Transform::Identity => (x, y),
+            Transform::RotateClockwise => (y, height - 1 - x),
+            Transform::Rotate180 => (width - 1 - x, height - 1 - y),
+            Transform::RotateCounterclockwise => (width - 1 - y, x),
+            Transform::FlipHorizontal => (width - 1 - x, y),
+            Transform::FlipVertical => (x, height - 1 - y),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (width - 1 - y, height - 1 - x),
+        }
+    }
+}
+
+/// A read-only window over a transformed grid, repeating it if the window is larger than the
+/// grid. Nothing is copied until `to_grid` is called.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    transform: Transform,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get<N>(&self, coord: Coordinates<N>) -> Option<&'a T>
+    where
+        N: Num + Copy + TryInto<usize>,
+    {
+        let x: usize = coord.x().try_into().ok()?;
+        let y: usize = coord.y().try_into().ok()?;
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let (width, height) = self.transform.size(self.grid.width, self.grid.height);
+        let (x, y) = ((self.x + x) % width, (self.y + y) % height);
+        let (x, y) = self
+            .transform
+            .source(x, y, self.grid.width, self.grid.height);
+        self.grid.get(Coordinates::new(x, y))
+    }
+
+    /// A smaller window with its top left corner at `(x, y)` of this one.
+    ///
+    /// Panics if it doesn't fit in this view.
+    pub fn crop(self, x: usize, y: usize, width: usize, height: usize) -> Self {
+        assert!(
+            x + width <= self.width && y + height <= self.height,
+            "a {width}x{height} crop at ({x}, {y}) doesn't fit in {}x{}",
+            self.width,
+            self.height
+        );
+        Self {
+            x: self.x + x,
+            y: self.y + y,
+            width,
+            height,
+            ..self
+        }
+    }
+
+    /// This view repeated `times_x` times horizontally and `times_y` vertically.
+    pub fn tiled(self, times_x: usize, times_y: usize) -> Self {
+        let (width, height) = self.transform.size(self.grid.width, self.grid.height);
+        assert!(
+            (self.width, self.height) == (width, height),
+            "only whole grids can be tiled"
+        );
+        Self {
+            width: self.width * times_x,
+            height: self.height * times_y,
+            ..self
+        }
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &'a T> + '_ {
+        (0..self.width).filter_map(move |x| self.get(Coordinates::new(x, y)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &'a T> + '_> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::new(self.width, self.height, cells)
+    }
+}
+
+impl<T> Display for View<'_, T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn to_i64<N>(coord: Coordinates<N>) -> Option<(i64, i64)>
+where
+    N: Num + Copy + TryInto<i64>,
+{
+    Some((coord.x().try_into().ok()?, coord.y().try_into().ok()?))
+}
+
+impl<T> Index<Coordinates<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coordinates<usize>) -> &Self::Output {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "{coord:?} is outside of a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coordinates<usize>> for Grid<T> {
+    fn index_mut(&mut self, coord: Coordinates<usize>) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = sample();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits.values().sum::<u32>(), 10);
+
+        let empty = Grid::parse("", |c| c);
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.rows().count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has a different width")]
+    fn test_parse_ragged() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = sample();
+        assert_eq!(grid.get(Coordinates::new(2, 0)), Some(&'c'));
+        assert_eq!(grid.get(Coordinates::new(0, 1)), Some(&'d'));
+        assert_eq!(grid.get(Coordinates::new(3, 0)), None);
+        assert_eq!(grid.get(Coordinates::new(0, 2)), None);
+        assert_eq!(grid.get(Coordinates::new(-1, 0)), None);
+        assert_eq!(grid.get(Coordinates::new(1i64, 1i64)), Some(&'e'));
+        assert!(grid.contains(Coordinates::new(1u8, 1u8)));
+
+        *grid.get_mut(Coordinates::new(1, 0)).unwrap() = 'x';
+        grid[Coordinates::new(2, 1)] = 'y';
+        assert_eq!(grid[Coordinates::new(1, 0)], 'x');
+        assert_eq!(grid.to_string(), "axc\ndey\n");
+        assert!(grid.get_mut(Coordinates::new(5, 5)).is_none());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = sample();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+
+        let mut reversed = grid.clone();
+        reversed.rows_mut().for_each(|row| row.reverse());
+        assert_eq!(reversed, grid.flip_horizontal());
+
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        assert_eq!(grid.column(1).rev().collect::<String>(), "eb");
+        assert_eq!(grid.column(3).count(), 0);
+
+        let cells: Vec<_> = grid.cells().map(|(c, v)| (c.x(), c.y(), *v)).collect();
+        assert_eq!(cells[4], (1, 1, 'e'));
+        assert_eq!(grid.position(|&c| c == 'f'), Some(Coordinates::new(2, 1)));
+        assert_eq!(grid.position(|&c| c == 'z'), None);
+
+        let mut upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.to_string(), "ABC\nDEF\n");
+        upper
+            .cells_mut()
+            .filter(|(c, _)| c.x() == c.y())
+            .for_each(|(_, v)| *v = '.');
+        assert_eq!(upper.to_string(), ".BC\nD.F\n");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse("abc\ndef\nghi", |c| c);
+        let values = |coords: Vec<Coordinates<i32>>| -> String {
+            coords
+                .into_iter()
+                .map(|c| *grid.get_wrapping(c).unwrap())
+                .collect()
+        };
+
+        let corner = Coordinates::new(0, 0);
+        assert_eq!(values(grid.orthogonal_neighbors(corner).collect()), "bd");
+        assert_eq!(values(grid.adjacent_neighbors(corner).collect()), "bed");
+        assert_eq!(
+            values(
+                grid.neighbors(corner, ORTHOGONAL, Edges::Wrapping)
+                    .collect()
+            ),
+            "gbdc"
+        );
+        assert_eq!(
+            values(
+                grid.neighbors(Coordinates::new(1, 1), DIAGONAL, Edges::Bounded)
+                    .collect()
+            ),
+            "ciga"
+        );
+        assert_eq!(
+            grid.neighbors(corner, ORTHOGONAL, Edges::Unbounded)
+                .collect::<Vec<_>>(),
+            vec![
+                Coordinates::new(0, -1),
+                Coordinates::new(1, 0),
+                Coordinates::new(0, 1),
+                Coordinates::new(-1, 0)
+            ]
+        );
+        // Negative coordinates don't fit in a usize even when unbounded.
+        assert_eq!(
+            grid.neighbors(
+                Coordinates::new(0usize, 0usize),
+                ORTHOGONAL,
+                Edges::Unbounded
+            )
+            .count(),
+            2
+        );
+
+        assert_eq!(
+            grid.wrap(Coordinates::new(-1, 7)),
+            Some(Coordinates::new(2, 1))
+        );
+        assert_eq!(
+            grid.get_wrapping(Coordinates::new(-4i64, -3i64)),
+            Some(&'c')
+        );
+    }
+
+    #[test]
+    fn test_wrap_empty() {
+        let grid = Grid::parse("", |c| c);
+        assert_eq!(grid.wrap(Coordinates::new(3, -2)), None);
+        assert_eq!(grid.get_wrapping(Coordinates::new(0, 0)), None);
+        assert_eq!(
+            grid.neighbors(Coordinates::new(0, 0), ORTHOGONAL, Edges::Wrapping)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::parse("a.#\n...\n#.b", |c| c);
+        let ray: String = grid
+            .ray(Coordinates::new(0, 0), Direction::Right)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(ray, ".#");
+        assert_eq!(grid.ray(Coordinates::new(0, 0), Direction::Up).count(), 0);
+        assert_eq!(
+            grid.ray(Coordinates::new(2, 2), Direction::Up)
+                .map(|(c, _)| c)
+                .collect::<Vec<_>>(),
+            vec![Coordinates::new(2, 1), Coordinates::new(2, 0)]
+        );
+
+        assert_eq!(
+            grid.cast(Coordinates::new(0, 0), Direction::Down, |&c| c == '#'),
+            Some((Coordinates::new(0, 2), &'#'))
+        );
+        assert_eq!(
+            grid.cast(Coordinates::new(1, 0), Direction::Down, |&c| c == '#'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = Grid::parse("abc\ndef", |c| c);
+        let transformed = |transform| grid.transformed(transform).to_string();
+
+        assert_eq!(transformed(Transform::Identity), "abc\ndef\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_180().to_string(), "fed\ncba\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(transformed(Transform::AntiTranspose), "fc\neb\nda\n");
+
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(
+            grid.rotate_clockwise().rotate_clockwise(),
+            grid.rotate_180()
+        );
+        assert_eq!(grid.transpose().transpose(), grid);
+        for transform in Transform::ALL {
+            assert_eq!(
+                grid.view(transform).to_string(),
+                grid.transformed(transform).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse("abc\ndef\nghi", |c| c);
+
+        let crop = grid.crop(1, 1, 2, 2);
+        assert_eq!((crop.width(), crop.height()), (2, 2));
+        assert_eq!(crop.to_string(), "ef\nhi\n");
+        assert_eq!(crop.get(Coordinates::new(0, 1)), Some(&'h'));
+        assert_eq!(crop.get(Coordinates::new(2, 0)), None);
+        assert_eq!(crop.crop(1, 0, 1, 2).to_string(), "f\ni\n");
+
+        let rotated = grid.view(Transform::RotateClockwise).crop(0, 0, 2, 1);
+        assert_eq!(rotated.to_string(), "gd\n");
+
+        let tiled = grid.crop(0, 0, 3, 3).tiled(2, 1);
+        assert_eq!(tiled.to_string(), "abcabc\ndefdef\nghighi\n");
+        assert_eq!(grid.tiled(2, 2).crop(2, 2, 2, 2).to_string(), "ig\nca\n");
+        assert_eq!(grid.tiled(1, 2).to_grid().height(), 6);
+    }
+}
@@ -0,0 +1,300 @@
+//! Hexagonal grids in axial coordinates `(q, r)`, the third cube coordinate being
+//! `s = -q - r`. Flat-topped grids move `n`/`ne`/`se`/`s`/`sw`/`nw` and pointy-topped ones
+//! `e`/`se`/`sw`/`w`/`nw`/`ne`; both step with [`Hex::step`].
+
+use std::{
+    collections::HashSet,
+    fmt::{Display, Formatter},
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+use num::{Integer, Signed};
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub struct Hex<T>
+where
+    T: Integer,
+{
+    q: T,
+    r: T,
+}
+
+impl<T> Hex<T>
+where
+    T: Integer + Signed + Copy,
+{
+    pub fn new(q: T, r: T) -> Self {
+        Self { q, r }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(num::zero(), num::zero())
+    }
+
+    /// Panics unless `q + r + s == 0`.
+    pub fn from_cube(q: T, r: T, s: T) -> Self {
+        assert!(
+            (q + r + s).is_zero(),
+            "cube coordinates must add up to zero"
+        );
+        Self::new(q, r)
+    }
+
+    pub fn q(&self) -> T {
+        self.q
+    }
+
+    pub fn r(&self) -> T {
+        self.r
+    }
+
+    pub fn s(&self) -> T {
+        -self.q - self.r
+    }
+
+    pub fn cube(&self) -> (T, T, T) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn step(&self, direction: impl Into<Hex<T>>) -> Self {
+        *self + direction.into()
+    }
+
+    pub fn step_by(&self, direction: impl Into<Hex<T>>, steps: T) -> Self {
+        let offset = direction.into();
+        *self + Self::new(offset.q * steps, offset.r * steps)
+    }
+
+    /// Number of steps between two hexes.
+    pub fn distance(&self, other: Self) -> T {
+        let delta = *self - other;
+        (delta.q.abs() + delta.r.abs() + delta.s().abs()) / (num::one::<T>() + num::one())
+    }
+}
+
+impl<T> Hex<T>
+where
+    T: Integer + Signed + Copy + Hash,
+{
+    pub fn neighbors(&self) -> HashSet<Self> {
+        HexDirection::ALL
+            .iter()
+            .map(|&direction| self.step(direction))
+            .collect()
+    }
+}
+
+impl<T> Add for Hex<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q + rhs.q,
+            r: self.r + rhs.r,
+        }
+    }
+}
+
+impl<T> Sub for Hex<T>
+where
+    T: Integer,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q - rhs.q,
+            r: self.r - rhs.r,
+        }
+    }
+}
+
+fn unit<T>(offset: i8) -> T
+where
+    T: Integer + Signed,
+{
+    match offset {
+        -1 => -T::one(),
+        0 => T::zero(),
+        _ => T::one(),
+    }
+}
+
+/// Directions of a flat-topped hex grid, where columns are straight.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum HexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+/// Directions of a pointy-topped hex grid, where rows are straight.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum PointyHexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+/// 60° turns, reversal and parsing, shared by both orientations listed clockwise in `ALL`.
+macro_rules! hex_direction {
+    ($name:ident, [$($variant:ident => $notation:literal, ($q:literal, $r:literal)),*]) => {
+        impl $name {
+            /// Clockwise.
+            pub const ALL: [$name; 6] = [$($name::$variant),*];
+
+            fn index(self) -> usize {
+                self as usize
+            }
+
+            pub fn turn_right(&self) -> $name {
+                $name::ALL[(self.index() + 1) % 6]
+            }
+
+            pub fn turn_left(&self) -> $name {
+                $name::ALL[(self.index() + 5) % 6]
+            }
+
+            pub fn reverse_direction(&self) -> $name {
+                $name::ALL[(self.index() + 3) % 6]
+            }
+
+            /// Reads `ne`, `SW`... as in the puzzles.
+            pub fn parse(notation: &str) -> Option<$name> {
+                match notation.to_ascii_lowercase().as_str() {
+                    $($notation => Some($name::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// Reads a path of directions written without separators, like `esenee`.
+            pub fn parse_path(path: &str) -> Option<Vec<$name>> {
+                let mut directions = Vec::new();
+                let mut rest = path.trim();
+                while !rest.is_empty() {
+                    let len = if rest.get(..2).and_then($name::parse).is_some() { 2 } else { 1 };
+                    directions.push($name::parse(rest.get(..len)?)?);
+                    rest = &rest[len..];
+                }
+                Some(directions)
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                let s = match self {
+                    $($name::$variant => $notation,)*
+                };
+                write!(f, "{s}")
+            }
+        }
+
+        impl<T> From<$name> for Hex<T>
+        where
+            T: Integer + Signed + Copy,
+        {
+            fn from(direction: $name) -> Self {
+                match direction {
+                    $($name::$variant => Hex::new(unit($q), unit($r)),)*
+                }
+            }
+        }
+    };
+}
+
+hex_direction!(HexDirection, [
+    North => "n", (0, -1),
+    NorthEast => "ne", (1, -1),
+    SouthEast => "se", (1, 0),
+    South => "s", (0, 1),
+    SouthWest => "sw", (-1, 1),
+    NorthWest => "nw", (-1, 0)
+]);
+
+hex_direction!(PointyHexDirection, [
+    East => "e", (1, 0),
+    SouthEast => "se", (0, 1),
+    SouthWest => "sw", (-1, 1),
+    West => "w", (-1, 0),
+    NorthWest => "nw", (0, -1),
+    NorthEast => "ne", (1, -1)
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(HexDirection::parse("NE"), Some(HexDirection::NorthEast));
+        assert_eq!(HexDirection::parse("e"), None);
+        assert_eq!(
+            PointyHexDirection::parse("e"),
+            Some(PointyHexDirection::East)
+        );
+        assert_eq!(
+            PointyHexDirection::parse_path("esenee"),
+            Some(vec![
+                PointyHexDirection::East,
+                PointyHexDirection::SouthEast,
+                PointyHexDirection::NorthEast,
+                PointyHexDirection::East,
+            ])
+        );
+        assert_eq!(PointyHexDirection::parse_path("ex"), None);
+        for direction in HexDirection::ALL {
+            assert_eq!(HexDirection::parse(&direction.to_string()), Some(direction));
+        }
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(HexDirection::North.turn_right(), HexDirection::NorthEast);
+        assert_eq!(HexDirection::North.turn_left(), HexDirection::NorthWest);
+        assert_eq!(
+            PointyHexDirection::East.reverse_direction(),
+            PointyHexDirection::West
+        );
+
+        for direction in HexDirection::ALL {
+            let there: Hex<i32> = Hex::origin().step(direction);
+            assert_eq!(there.step(direction.reverse_direction()), Hex::origin());
+            assert_eq!(there.distance(Hex::origin()), 1);
+        }
+        for direction in PointyHexDirection::ALL {
+            let there: Hex<i32> = Hex::origin().step(direction);
+            assert_eq!(there.step(direction.reverse_direction()), Hex::origin());
+        }
+    }
+
+    #[test]
+    fn test_distance() {
+        // 2017 day 11
+        let walk = |path: &str| {
+            path.split(',')
+                .map(|step| HexDirection::parse(step).unwrap())
+                .fold(Hex::<i32>::origin(), |hex, direction| hex.step(direction))
+                .distance(Hex::origin())
+        };
+        assert_eq!(walk("ne,ne,ne"), 3);
+        assert_eq!(walk("ne,ne,sw,sw"), 0);
+        assert_eq!(walk("ne,ne,s,s"), 2);
+        assert_eq!(walk("se,sw,se,sw,sw"), 3);
+
+        let hex: Hex<i64> = Hex::from_cube(1, -3, 2);
+        assert_eq!(hex.cube(), (1, -3, 2));
+        assert_eq!(hex.step_by(HexDirection::South, 3), Hex::new(1, 0));
+        assert_eq!(hex.neighbors().len(), 6);
+        assert!(hex.neighbors().iter().all(|n| n.distance(hex) == 1));
+    }
+}
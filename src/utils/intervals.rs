@@ -0,0 +1,346 @@
+//! Sets of integers stored as sorted disjoint ranges, and piecewise maps that shift parts of
+//! them, for puzzles whose values are far too many to handle one by one.
+
+use std::ops::{Range, RangeInclusive};
+
+use num::Integer;
+
+/// A set of integers as half-open ranges, kept sorted with neither overlapping nor adjacent
+/// ranges, so that equal sets compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Integer + Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sorts and merges arbitrary ranges, dropping empty ones.
+    fn normalised(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|range| range.start < range.end);
+        ranges.sort_unstable_by_key(|range| range.start);
+
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalised(ranges);
+    }
+
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        self.insert(*range.start()..*range.end() + T::one());
+    }
+
+    /// The disjoint ranges in increasing order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn total_len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |acc, range| acc + range.end - range.start)
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    /// Whether every integer of `range` is in the set.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.start >= range.end {
+            return true;
+        }
+        let index = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|other| other.start <= range.start && range.end <= other.end)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalised(self.ranges.iter().chain(&other.ranges).cloned().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The integers of `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(cut) = other.ranges.get(j).filter(|cut| cut.start < range.end) {
+                if cut.start > start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+                if cut.end > range.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The integers below `at` and the others.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+        for range in &self.ranges {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if at <= range.start {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+}
+
+impl<T> From<Range<T>> for IntervalSet<T>
+where
+    T: Integer + Copy,
+{
+    fn from(range: Range<T>) -> Self {
+        Self::normalised(vec![range])
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for IntervalSet<T>
+where
+    T: Integer + Copy,
+{
+    fn from(range: RangeInclusive<T>) -> Self {
+        Self::from(*range.start()..*range.end() + T::one())
+    }
+}
+
+impl<T> FromIterator<Range<T>> for IntervalSet<T>
+where
+    T: Integer + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalised(iter.into_iter().collect())
+    }
+}
+
+impl<T> FromIterator<RangeInclusive<T>> for IntervalSet<T>
+where
+    T: Integer + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        iter.into_iter()
+            .map(|range| *range.start()..*range.end() + T::one())
+            .collect()
+    }
+}
+
+/// Sends `source..source + length` to `destination..destination + length`, as written in
+/// the almanac of 2023 day 5.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangeMapping<T> {
+    pub destination: T,
+    pub source: T,
+    pub length: T,
+}
+
+impl<T> RangeMapping<T>
+where
+    T: Integer + Copy,
+{
+    pub fn new(destination: T, source: T, length: T) -> Self {
+        Self {
+            destination,
+            source,
+            length,
+        }
+    }
+
+    pub fn source_range(&self) -> Range<T> {
+        self.source..self.source + self.length
+    }
+
+    /// Where `value` goes, if it is in the source range.
+    pub fn apply(&self, value: T) -> Option<T> {
+        self.source_range()
+            .contains(&value)
+            .then(|| self.destination + (value - self.source))
+    }
+}
+
+/// A piecewise map made of [`RangeMapping`]s, leaving the values none of them covers where
+/// they are. When sources overlap the first mapping wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    mappings: Vec<RangeMapping<T>>,
+}
+
+impl<T> RangeMap<T>
+where
+    T: Integer + Copy,
+{
+    pub fn new(mappings: Vec<RangeMapping<T>>) -> Self {
+        Self { mappings }
+    }
+
+    pub fn mappings(&self) -> &[RangeMapping<T>] {
+        &self.mappings
+    }
+
+    pub fn apply(&self, value: T) -> T {
+        self.mappings
+            .iter()
+            .find_map(|mapping| mapping.apply(value))
+            .unwrap_or(value)
+    }
+
+    /// Where every integer of `set` goes, splitting ranges across mappings.
+    pub fn apply_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut remaining = set.clone();
+        let mut ranges = Vec::new();
+        for mapping in &self.mappings {
+            let source = IntervalSet::from(mapping.source_range());
+            for range in remaining.intersection(&source).iter() {
+                let start = mapping.destination + (range.start - mapping.source);
+                ranges.push(start..start + (range.end - range.start));
+            }
+            remaining = remaining.difference(&source);
+        }
+        ranges.extend(remaining.ranges);
+        IntervalSet::normalised(ranges)
+    }
+}
+
+impl<T> FromIterator<RangeMapping<T>> for RangeMap<T>
+where
+    T: Integer + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = RangeMapping<T>>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise() {
+        let set: IntervalSet<u64> = [10..=14, 3..=5, 16..=20, 12..=18].into_iter().collect();
+        assert_eq!(set.ranges(), &[3..6, 10..21]);
+        assert_eq!(set.total_len(), 14);
+        assert_eq!((set.min(), set.max()), (Some(3), Some(20)));
+        assert!(set.contains(&5));
+        assert!(!set.contains(&8));
+        assert!(set.contains(&17));
+        assert!(!set.contains(&21));
+        assert!(set.contains_range(&(11..20)));
+        assert!(!set.contains_range(&(5..11)));
+
+        let mut set: IntervalSet<i32> = (0..2).into();
+        set.insert(2..4);
+        set.insert(7..7);
+        set.insert_inclusive(-3..=-2);
+        assert_eq!(set.ranges(), &[-3..-1, 0..4]);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<i32> = [0..10, 20..30].into_iter().collect();
+        let b: IntervalSet<i32> = [5..25, 28..40].into_iter().collect();
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b).ranges(), &[5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), &[0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), &[10..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+
+        let (below, above) = a.split_at(25);
+        assert_eq!(below.ranges(), &[0..10, 20..25]);
+        assert_eq!(above, IntervalSet::from(25..30));
+    }
+
+    #[test]
+    fn test_range_map() {
+        let map: RangeMap<u64> = [RangeMapping::new(50, 98, 2), RangeMapping::new(52, 50, 48)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.apply(49), 49);
+        assert_eq!(map.apply(50), 52);
+        assert_eq!(map.apply(97), 99);
+        assert_eq!(map.apply(98), 50);
+        assert_eq!(map.apply(100), 100);
+
+        let seeds: IntervalSet<u64> = [79..93, 55..68, 95..105].into_iter().collect();
+        assert_eq!(
+            map.apply_set(&seeds).ranges(),
+            &[50..52, 57..70, 81..95, 97..105]
+        );
+        assert_eq!(map.apply_set(&seeds).total_len(), seeds.total_len());
+    }
+}
@@ -1,9 +1,125 @@
-use std::{fs, io::{self, Write}, thread::sleep, time::Duration};
+use std::{
+    fmt::{Display, Formatter},
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    thread::sleep,
+    time::Duration,
+};
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const DEFAULT_INPUT_DIR: &str = "./src/inputs";
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingDirectory(PathBuf),
+    MissingFile(PathBuf),
+    Empty(String),
+    Unreadable(String, io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingDirectory(dir) => write!(
+                f,
+                "input directory {} does not exist, point {} or --input-dir to it",
+                dir.display(),
+                INPUT_DIR_VAR
+            ),
+            InputError::MissingFile(path) => write!(f, "input file {} not found", path.display()),
+            InputError::Empty(source) => write!(f, "input from {source} is empty"),
+            InputError::Unreadable(source, e) => write!(f, "unable to read {source}: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputProvider {
+    Directory(PathBuf),
+    Stdin,
+}
+
+impl InputProvider {
+    pub fn directory(dir: impl Into<PathBuf>) -> Self {
+        InputProvider::Directory(dir.into())
+    }
+
+    /// Reads inputs from the directory in `AOC_INPUT_DIR`, falling back to `./src/inputs`.
+    pub fn from_env() -> Self {
+        let dir = std::env::var_os(INPUT_DIR_VAR).unwrap_or(DEFAULT_INPUT_DIR.into());
+        Self::directory(dir)
+    }
+
+    pub fn dir(&self) -> Option<&Path> {
+        match self {
+            InputProvider::Directory(dir) => Some(dir),
+            InputProvider::Stdin => None,
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> Option<PathBuf> {
+        self.dir().map(|dir| {
+            dir.join(format!("y{}", year))
+                .join(format!("day{:02}", day))
+        })
+    }
+
+    pub fn read(&self, year: u32, day: u32) -> Result<String, InputError> {
+        let raw = match (self.dir(), self.path(year, day)) {
+            (Some(dir), Some(path)) => {
+                if !dir.is_dir() {
+                    return Err(InputError::MissingDirectory(dir.to_path_buf()));
+                }
+                match fs::read_to_string(&path) {
+                    Ok(raw) => raw,
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                        return Err(InputError::MissingFile(path))
+                    }
+                    Err(e) => return Err(InputError::Unreadable(path.display().to_string(), e)),
+                }
+            }
+            _ => {
+                let mut raw = String::new();
+                io::stdin()
+                    .read_to_string(&mut raw)
+                    .map_err(|e| InputError::Unreadable("stdin".to_string(), e))?;
+                raw
+            }
+        };
+
+        let input = normalize(&raw);
+        if input.is_empty() {
+            return Err(InputError::Empty(self.describe(year, day)));
+        }
+        Ok(input)
+    }
+
+    fn describe(&self, year: u32, day: u32) -> String {
+        match self.path(year, day) {
+            Some(path) => path.display().to_string(),
+            None => "stdin".to_string(),
+        }
+    }
+}
+
+/// Converts CRLF line endings to LF and leaves exactly one trailing newline, or none if the
+/// input has no content.
+pub fn normalize(raw: &str) -> String {
+    let mut input = raw.replace("\r\n", "\n");
+    input.truncate(input.trim_end_matches(['\n', '\r']).len());
+    if !input.is_empty() {
+        input.push('\n');
+    }
+    input
+}
 
 pub fn read(year: u32, day: u32) -> String {
-    let path = format!("./src/inputs/y{}/day{:02}", year, day);
-    println!("{}", path);
-    fs::read_to_string(path).expect("Unable to read file")
+    InputProvider::from_env()
+        .read(year, day)
+        .unwrap_or_else(|e| panic!("{e}"))
 }
 
 pub fn animate(input: &str) {
@@ -11,3 +127,50 @@ pub fn animate(input: &str) {
     io::stdout().flush().unwrap();
     sleep(Duration::from_millis(100));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\r\n"), "a\nb\n");
+        assert_eq!(normalize("a\nb"), "a\nb\n");
+        assert_eq!(normalize("a\n\nb\n\n\n"), "a\n\nb\n");
+        assert_eq!(normalize("\n\r\n"), "");
+    }
+
+    #[test]
+    fn test_path() {
+        let provider = InputProvider::directory("inputs");
+        assert_eq!(
+            provider.path(2023, 5),
+            Some(PathBuf::from("inputs/y2023/day05"))
+        );
+        assert_eq!(InputProvider::Stdin.path(2023, 5), None);
+    }
+
+    #[test]
+    fn test_read_errors() {
+        let dir = std::env::temp_dir().join(format!("aoc_io_test_{}", std::process::id()));
+        let provider = InputProvider::directory(&dir);
+        assert!(matches!(
+            provider.read(2023, 1),
+            Err(InputError::MissingDirectory(_))
+        ));
+
+        fs::create_dir_all(dir.join("y2023")).unwrap();
+        assert!(matches!(
+            provider.read(2023, 1),
+            Err(InputError::MissingFile(_))
+        ));
+
+        fs::write(dir.join("y2023").join("day01"), "\r\n").unwrap();
+        assert!(matches!(provider.read(2023, 1), Err(InputError::Empty(_))));
+
+        fs::write(dir.join("y2023").join("day01"), "1\r\n2").unwrap();
+        assert_eq!(provider.read(2023, 1).unwrap(), "1\n2\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -67,7 +67,7 @@ impl<T: Eq + Hash> MultiSet<T> {
     }
 
     pub fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I, count: usize) {
-        for i in iter{
+        for i in iter {
             self.insert(i, count);
         }
     }
@@ -137,23 +137,23 @@ impl<T: Eq + Hash> FromIterator<(T, usize)> for MultiSet<T> {
     }
 }
 
-impl <T: Eq + Hash + Copy> From<HashSet<T>> for MultiSet<T> {
+impl<T: Eq + Hash + Copy> From<HashSet<T>> for MultiSet<T> {
     fn from(value: HashSet<T>) -> Self {
         value.iter().copied().collect()
     }
 }
 
-impl <T: Eq + Hash + Copy> From<&HashSet<T>> for MultiSet<T> {
+impl<T: Eq + Hash + Copy> From<&HashSet<T>> for MultiSet<T> {
     fn from(value: &HashSet<T>) -> Self {
         value.iter().copied().collect()
     }
 }
 
-use std::ops::{Add, Sub, BitAnd, BitOr};
+use std::ops::{Add, BitAnd, BitOr, Sub};
 
 impl<T: Eq + Hash + Clone> Add for MultiSet<T> {
     type Output = Self;
-    
+
     fn add(self, rhs: Self) -> Self::Output {
         let mut result = self;
         for (elem, &count) in rhs.counts.iter() {
@@ -165,7 +165,7 @@ impl<T: Eq + Hash + Clone> Add for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> Add<&Self> for MultiSet<T> {
     type Output = Self;
-    
+
     fn add(self, rhs: &Self) -> Self::Output {
         let mut result = self;
         for (elem, &count) in rhs.counts.iter() {
@@ -177,7 +177,7 @@ impl<T: Eq + Hash + Clone> Add<&Self> for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> Sub for MultiSet<T> {
     type Output = Self;
-    
+
     fn sub(self, rhs: Self) -> Self::Output {
         self.difference(&rhs)
     }
@@ -185,7 +185,7 @@ impl<T: Eq + Hash + Clone> Sub for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> Sub<&Self> for MultiSet<T> {
     type Output = Self;
-    
+
     fn sub(self, rhs: &Self) -> Self::Output {
         self.difference(rhs)
     }
@@ -193,7 +193,7 @@ impl<T: Eq + Hash + Clone> Sub<&Self> for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> BitAnd for MultiSet<T> {
     type Output = Self;
-    
+
     fn bitand(self, rhs: Self) -> Self::Output {
         self.intersection(&rhs)
     }
@@ -201,7 +201,7 @@ impl<T: Eq + Hash + Clone> BitAnd for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> BitAnd<&Self> for MultiSet<T> {
     type Output = Self;
-    
+
     fn bitand(self, rhs: &Self) -> Self::Output {
         self.intersection(rhs)
     }
@@ -209,7 +209,7 @@ impl<T: Eq + Hash + Clone> BitAnd<&Self> for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> BitOr for MultiSet<T> {
     type Output = Self;
-    
+
     fn bitor(self, rhs: Self) -> Self::Output {
         self.union(&rhs)
     }
@@ -217,7 +217,7 @@ impl<T: Eq + Hash + Clone> BitOr for MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> BitOr<&Self> for MultiSet<T> {
     type Output = Self;
-    
+
     fn bitor(self, rhs: &Self) -> Self::Output {
         self.union(rhs)
     }
@@ -226,11 +226,11 @@ impl<T: Eq + Hash + Clone> BitOr<&Self> for MultiSet<T> {
 // Also implement for references
 impl<T: Eq + Hash + Clone> Add for &MultiSet<T> {
     type Output = MultiSet<T>;
-    
+
     fn add(self, rhs: Self) -> Self::Output {
         let mut result = self.clone();
         for (elem, &count) in rhs.counts.iter() {
-                result.insert(elem.clone(), count);
+            result.insert(elem.clone(), count);
         }
         result
     }
@@ -238,7 +238,7 @@ impl<T: Eq + Hash + Clone> Add for &MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> Sub for &MultiSet<T> {
     type Output = MultiSet<T>;
-    
+
     fn sub(self, rhs: Self) -> Self::Output {
         self.difference(rhs)
     }
@@ -246,7 +246,7 @@ impl<T: Eq + Hash + Clone> Sub for &MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> BitAnd for &MultiSet<T> {
     type Output = MultiSet<T>;
-    
+
     fn bitand(self, rhs: Self) -> Self::Output {
         self.intersection(rhs)
     }
@@ -254,7 +254,7 @@ impl<T: Eq + Hash + Clone> BitAnd for &MultiSet<T> {
 
 impl<T: Eq + Hash + Clone> BitOr for &MultiSet<T> {
     type Output = MultiSet<T>;
-    
+
     fn bitor(self, rhs: Self) -> Self::Output {
         self.union(rhs)
     }
@@ -369,7 +369,7 @@ mod tests {
 
         let union = ms1.union(&ms2);
 
-        assert_eq!(union.len(), 8); 
+        assert_eq!(union.len(), 8);
         assert_eq!(union.distinct_elements(), 3);
         assert_eq!(union.count(&1), 5);
         assert_eq!(union.count(&2), 1);
@@ -492,7 +492,7 @@ mod tests {
         ms2.insert(1, 5);
 
         let union = ms1.union(&ms2);
-        assert_eq!(union.count(&1), 7); 
+        assert_eq!(union.count(&1), 7);
     }
 
     #[test]
@@ -0,0 +1,458 @@
+//! Shortest paths over any hashable state. A search only needs a start, a closure listing the
+//! successors of a state (with the cost of the move for weighted searches) and a closure telling
+//! whether a state is a goal.
+//!
+//! ```ignore
+//! let path = search::dijkstra(
+//!     start,
+//!     |&(coord, direction)| moves(coord, direction),
+//!     |&(coord, _)| coord == goal,
+//! );
+//! ```
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use num::Zero;
+
+/// A cheapest way from the start to a goal, both included in `states`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("a path has at least its start")
+    }
+}
+
+/// States reached so far, each with its best cost and the states it was best reached from.
+struct Explored<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize>,
+    costs: Vec<C>,
+    parents: Vec<Vec<usize>>,
+    /// Whether to keep every parent giving the best cost, or only the first one found.
+    all: bool,
+}
+
+impl<S, C> Explored<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord,
+{
+    fn new(start: S, cost: C, all: bool) -> Self {
+        Self {
+            states: vec![start.clone()],
+            index: HashMap::from([(start, 0)]),
+            costs: vec![cost],
+            parents: vec![Vec::new()],
+            all,
+        }
+    }
+
+    /// Records reaching `state` from the state at `parent`, and returns its index when that's
+    /// the cheapest way found so far, so it has to be explored (again).
+    fn reach(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        let Some(&i) = self.index.get(&state) else {
+            let i = self.states.len();
+            self.index.insert(state.clone(), i);
+            self.states.push(state);
+            self.costs.push(cost);
+            self.parents.push(vec![parent]);
+            return Some(i);
+        };
+
+        if cost < self.costs[i] {
+            self.costs[i] = cost;
+            self.parents[i] = vec![parent];
+            Some(i)
+        } else {
+            if cost == self.costs[i] && self.all && !self.parents[i].contains(&parent) {
+                self.parents[i].push(parent);
+            }
+            None
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![self.states[goal].clone()];
+        let mut i = goal;
+        while let Some(&parent) = self.parents[i].first() {
+            states.push(self.states[parent].clone());
+            i = parent;
+        }
+        states.reverse();
+        Path {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+
+    fn paths(&self, goals: &[usize]) -> Vec<Path<S, C>> {
+        let mut paths = Vec::new();
+        let mut stack: Vec<Vec<usize>> = goals.iter().map(|&goal| vec![goal]).collect();
+        while let Some(reversed) = stack.pop() {
+            let last = *reversed.last().unwrap();
+            if self.parents[last].is_empty() {
+                paths.push(Path {
+                    cost: self.costs[reversed[0]],
+                    states: reversed
+                        .iter()
+                        .rev()
+                        .map(|&i| self.states[i].clone())
+                        .collect(),
+                });
+            }
+            for &parent in &self.parents[last] {
+                let mut longer = reversed.clone();
+                longer.push(parent);
+                stack.push(longer);
+            }
+        }
+        paths
+    }
+}
+
+/// Breadth-first search, where every move costs one.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (explored, goals) = breadth_first(start, successors, is_goal, false)?;
+    Some(explored.path(goals[0]))
+}
+
+/// Every shortest path of a breadth-first search, toward any of the closest goals.
+pub fn bfs_all<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Vec<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(start, successors, is_goal, true)
+        .map(|(explored, goals)| explored.paths(&goals))
+        .unwrap_or_default()
+}
+
+fn breadth_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Option<(Explored<S, usize>, Vec<usize>)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut explored = Explored::new(start, 0, all);
+    let mut queue = VecDeque::from([0]);
+    let mut goals = Vec::new();
+
+    while let Some(i) = queue.pop_front() {
+        let cost = explored.costs[i];
+        if goals
+            .first()
+            .is_some_and(|&goal| explored.costs[goal] < cost)
+        {
+            break;
+        }
+        if is_goal(&explored.states[i]) {
+            goals.push(i);
+            if !all {
+                break;
+            }
+            continue;
+        }
+
+        for next in successors(&explored.states[i].clone()) {
+            if let Some(next) = explored.reach(next, i, cost + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    (!goals.is_empty()).then_some((explored, goals))
+}
+
+/// Cheapest path with non-negative move costs.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// Every cheapest path, toward any of the cheapest goals.
+pub fn dijkstra_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Vec<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_all(start, successors, |_| C::zero(), is_goal)
+}
+
+/// Cheapest path, exploring first the states that `heuristic` estimates closer to a goal. The
+/// estimate must never exceed the actual remaining cost, or the path may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (explored, goals) = best_first(start, successors, heuristic, is_goal, false)?;
+    Some(explored.path(goals[0]))
+}
+
+/// Every cheapest path found by [`astar`], toward any of the cheapest goals.
+pub fn astar_all<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Vec<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    best_first(start, successors, heuristic, is_goal, true)
+        .map(|(explored, goals)| explored.paths(&goals))
+        .unwrap_or_default()
+}
+
+fn best_first<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    all: bool,
+) -> Option<(Explored<S, C>, Vec<usize>)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut open = BinaryHeap::from([Reverse((heuristic(&start), C::zero(), 0))]);
+    let mut explored = Explored::new(start, C::zero(), all);
+    let mut goals = Vec::new();
+
+    while let Some(Reverse((estimate, cost, i))) = open.pop() {
+        if cost > explored.costs[i] {
+            continue;
+        }
+        // Keep going after the first goal only to find the other paths as cheap as it.
+        if goals
+            .first()
+            .is_some_and(|&goal| explored.costs[goal] < estimate)
+        {
+            break;
+        }
+        if is_goal(&explored.states[i]) {
+            if !goals.contains(&i) {
+                goals.push(i);
+            }
+            if !all {
+                break;
+            }
+            continue;
+        }
+
+        for (next, step) in successors(&explored.states[i].clone()) {
+            let estimate = cost + step + heuristic(&next);
+            if let Some(next) = explored.reach(next, i, cost + step) {
+                open.push(Reverse((estimate, cost + step, next)));
+            }
+        }
+    }
+
+    (!goals.is_empty()).then_some((explored, goals))
+}
+
+/// Cheapest path when every move costs either 0 or 1, faster than [`dijkstra`] for those.
+/// Panics on any other cost.
+pub fn zero_one_bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut explored = Explored::new(start, 0, false);
+    let mut deque = VecDeque::from([(0, 0)]);
+
+    while let Some((cost, i)) = deque.pop_front() {
+        if cost > explored.costs[i] {
+            continue;
+        }
+        if is_goal(&explored.states[i]) {
+            return Some(explored.path(i));
+        }
+
+        for (next, step) in successors(&explored.states[i].clone()) {
+            assert!(step <= 1, "0-1 BFS moves cost 0 or 1, not {step}");
+            if let Some(next) = explored.reach(next, i, cost + step) {
+                match step {
+                    0 => deque.push_front((cost, next)),
+                    _ => deque.push_back((cost + 1, next)),
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{coords::Coordinates, grid::Grid};
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    fn walls() -> Grid<bool> {
+        Grid::parse(MAZE, |c| c == '#')
+    }
+
+    fn moves(walls: &Grid<bool>, coord: Coordinates<usize>) -> Vec<Coordinates<usize>> {
+        walls
+            .orthogonal_neighbors(coord)
+            .filter(|&next| !walls[next])
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let walls = walls();
+        let goal = Coordinates::new(7, 4);
+
+        let path = bfs(Coordinates::origin(), |&c| moves(&walls, c), |&c| c == goal).unwrap();
+        assert_eq!(path.cost, 15);
+        assert_eq!(path.states.len(), 16);
+        assert_eq!(path.states[0], Coordinates::origin());
+        assert_eq!(*path.goal(), goal);
+        assert!(path
+            .states
+            .windows(2)
+            .all(|pair| moves(&walls, pair[0]).contains(&pair[1])));
+
+        assert_eq!(bfs(0, |&n| [n + 1], |&n| n == 0).unwrap().states, vec![0]);
+        assert_eq!(bfs(0u8, |&n| (n < 5).then_some(n + 1), |&n| n == 9), None);
+    }
+
+    #[test]
+    fn test_bfs_all() {
+        // Every monotonic path in a 3x3 grid.
+        let paths = bfs_all(
+            (0, 0),
+            |&(x, y)| {
+                [(x + 1, y), (x, y + 1)]
+                    .into_iter()
+                    .filter(|&(x, y)| x <= 2 && y <= 2)
+            },
+            |&state| state == (2, 2),
+        );
+        assert_eq!(paths.len(), 6);
+        assert!(paths.iter().all(|path| path.cost == 4));
+    }
+
+    #[test]
+    fn test_weighted() {
+        // 2023 day 17 without the turning rules, the cost of a move is the digit moved onto.
+        let grid = Grid::parse("2413\n3215\n3255", |c| c.to_digit(10).unwrap());
+        let goal = Coordinates::new(3, 2);
+        let successors = |&coord: &Coordinates<usize>| {
+            grid.orthogonal_neighbors(coord)
+                .map(|next| (next, grid[next]))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(Coordinates::origin(), successors, |&c| c == goal).unwrap();
+        assert_eq!(path.cost, 16);
+        assert_eq!(
+            path.states
+                .windows(2)
+                .map(|pair| grid[pair[1]])
+                .sum::<u32>(),
+            16
+        );
+
+        let heuristic =
+            |coord: &Coordinates<usize>| (goal.x() - coord.x() + goal.y() - coord.y()) as u32;
+        let path = astar(Coordinates::origin(), successors, heuristic, |&c| c == goal).unwrap();
+        assert_eq!(path.cost, 16);
+
+        let paths = dijkstra_all(Coordinates::origin(), successors, |&c| c == goal);
+        assert!(!paths.is_empty());
+        assert!(paths.iter().all(|path| path.cost == 16));
+        assert_eq!(
+            astar_all(Coordinates::origin(), successors, heuristic, |&c| c == goal).len(),
+            paths.len()
+        );
+    }
+
+    #[test]
+    fn test_zero_one_bfs() {
+        // Walking through walls costs one, the rest is free.
+        let walls = walls();
+        let goal = Coordinates::new(7, 4);
+        let successors = |&coord: &Coordinates<usize>| {
+            walls
+                .orthogonal_neighbors(coord)
+                .map(|next| (next, walls[next] as usize))
+                .collect::<Vec<_>>()
+        };
+
+        let path = zero_one_bfs(Coordinates::origin(), successors, |&c| c == goal).unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states.len(), 16);
+        let path = zero_one_bfs(Coordinates::origin(), successors, |&c| {
+            c == Coordinates::new(6, 4)
+        })
+        .unwrap();
+        assert_eq!(path.cost, 1);
+
+        // Doubling is free, adding one costs one.
+        let moves = |&n: &u32| {
+            [(n + 1, 1), (n * 2, 0)]
+                .into_iter()
+                .filter(|&(n, _)| n <= 12)
+        };
+        assert_eq!(zero_one_bfs(1, moves, |&n| n == 12).unwrap().cost, 1);
+    }
+}
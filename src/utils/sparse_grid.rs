@@ -0,0 +1,390 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+};
+
+use num::Num;
+
+use super::{
+    coords::Coordinates,
+    grid::{Grid, ADJACENT, ORTHOGONAL},
+};
+
+/// An unbounded grid storing only the cells that were set, in a `HashMap`.
+///
+/// Cells are addressed like in [`Grid`], `x` being the column and `y` the row, but any
+/// coordinate is valid, negative ones included. The bounding box of the stored cells is kept up
+/// to date to print or convert the occupied region. Cells that were never set read as the
+/// default value, if the grid has one.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coordinates<i64>, T>,
+    default: Option<T>,
+    bounds: Option<(Coordinates<i64>, Coordinates<i64>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            default: None,
+            bounds: None,
+        }
+    }
+
+    /// A grid where every cell that was never set reads as `default`.
+    pub fn with_default(default: T) -> Self {
+        Self {
+            default: Some(default),
+            ..Self::new()
+        }
+    }
+
+    /// Keeps the cells for which `f` returns a value.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Self {
+        let mut grid = Self::new();
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Some(value) = f(c) {
+                    grid.insert(Coordinates::new(x as i64, y as i64), value);
+                }
+            }
+        }
+        grid
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Top left and bottom right corners of the stored cells, both included.
+    pub fn bounds(&self) -> Option<(Coordinates<i64>, Coordinates<i64>)> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.x() - min.x() + 1) as usize)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds
+            .map_or(0, |(min, max)| (max.y() - min.y() + 1) as usize)
+    }
+
+    /// Whether a value is stored at `coord`, the default doesn't count.
+    pub fn contains<N>(&self, coord: Coordinates<N>) -> bool
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        to_i64(coord).is_some_and(|coord| self.cells.contains_key(&coord))
+    }
+
+    pub fn get<N>(&self, coord: Coordinates<N>) -> Option<&T>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        to_i64(coord)
+            .and_then(|coord| self.cells.get(&coord))
+            .or(self.default.as_ref())
+    }
+
+    /// Only stored values can be modified, see [`SparseGrid::get_or_insert_with`].
+    pub fn get_mut<N>(&mut self, coord: Coordinates<N>) -> Option<&mut T>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        self.cells.get_mut(&to_i64(coord)?)
+    }
+
+    /// Panics if `coord` doesn't fit in an `i64`.
+    pub fn insert<N>(&mut self, coord: Coordinates<N>, value: T) -> Option<T>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        let coord = to_i64(coord).expect("coordinates don't fit in an i64");
+        self.bounds = Some(extend_bounds(self.bounds, coord));
+        self.cells.insert(coord, value)
+    }
+
+    pub fn get_or_insert_with<N>(&mut self, coord: Coordinates<N>, f: impl FnOnce() -> T) -> &mut T
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        if !self.contains(coord) {
+            self.insert(coord, f());
+        }
+        self.get_mut(coord).unwrap()
+    }
+
+    /// Shrinks the bounding box when the removed cell was on its edge.
+    pub fn remove<N>(&mut self, coord: Coordinates<N>) -> Option<T>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        let coord = to_i64(coord)?;
+        let value = self.cells.remove(&coord)?;
+        if let Some((min, max)) = self.bounds {
+            if [min.x(), max.x()].contains(&coord.x()) || [min.y(), max.y()].contains(&coord.y()) {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &coord| Some(extend_bounds(bounds, coord)));
+            }
+        }
+        Some(value)
+    }
+
+    /// Stored cells, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (Coordinates<i64>, &T)> {
+        self.cells.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// Cells at the given offsets from `coord` holding a value, stored or default.
+    pub fn neighbors<'a, N>(
+        &'a self,
+        coord: Coordinates<N>,
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (Coordinates<i64>, &'a T)> + 'a
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        let coord = to_i64(coord);
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let coord = coord?;
+            let neighbor = Coordinates::new(coord.x() + dx, coord.y() + dy);
+            self.get(neighbor).map(|value| (neighbor, value))
+        })
+    }
+
+    pub fn orthogonal_neighbors<N>(
+        &self,
+        coord: Coordinates<N>,
+    ) -> impl Iterator<Item = (Coordinates<i64>, &T)>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        self.neighbors(coord, ORTHOGONAL)
+    }
+
+    pub fn adjacent_neighbors<N>(
+        &self,
+        coord: Coordinates<N>,
+    ) -> impl Iterator<Item = (Coordinates<i64>, &T)>
+    where
+        N: Num + Copy + TryInto<i64>,
+    {
+        self.neighbors(coord, ADJACENT)
+    }
+
+    /// Draws the bounding box row by row, `f` getting `None` for cells without a value.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        let mut s = String::new();
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                s.push(f(self.get(Coordinates::new(x, y))));
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl<T> SparseGrid<T>
+where
+    T: Clone,
+{
+    /// The bounding box as a dense grid, its top left cell being the first corner of
+    /// [`SparseGrid::bounds`]. Cells without a value get `fill`.
+    pub fn to_grid(&self, fill: T) -> Grid<T> {
+        let Some((min, _)) = self.bounds else {
+            return Grid::new(0, 0, Vec::new());
+        };
+        let mut grid = Grid::filled(self.width(), self.height(), fill);
+        for (coord, value) in grid.cells_mut() {
+            let coord = Coordinates::new(coord.x() as i64 + min.x(), coord.y() as i64 + min.y());
+            if let Some(stored) = self.get(coord) {
+                *value = stored.clone();
+            }
+        }
+        grid
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T>
+where
+    T: Clone,
+{
+    fn from(grid: Grid<T>) -> Self {
+        grid.cells()
+            .map(|(coord, value)| (coord, value.clone()))
+            .collect()
+    }
+}
+
+impl<T, N> FromIterator<(Coordinates<N>, T)> for SparseGrid<T>
+where
+    N: Num + Copy + TryInto<i64>,
+{
+    fn from_iter<I: IntoIterator<Item = (Coordinates<N>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T, N> Extend<(Coordinates<N>, T)> for SparseGrid<T>
+where
+    N: Num + Copy + TryInto<i64>,
+{
+    fn extend<I: IntoIterator<Item = (Coordinates<N>, T)>>(&mut self, iter: I) {
+        for (coord, value) in iter {
+            self.insert(coord, value);
+        }
+    }
+}
+
+/// Cells without a value are drawn as `.`.
+impl<T> Display for SparseGrid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y()..=max.y() {
+            for x in min.x()..=max.x() {
+                match self.get(Coordinates::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn extend_bounds(
+    bounds: Option<(Coordinates<i64>, Coordinates<i64>)>,
+    coord: Coordinates<i64>,
+) -> (Coordinates<i64>, Coordinates<i64>) {
+    match bounds {
+        None => (coord, coord),
+        Some((min, max)) => (
+            Coordinates::new(min.x().min(coord.x()), min.y().min(coord.y())),
+            Coordinates::new(max.x().max(coord.x()), max.y().max(coord.y())),
+        ),
+    }
+}
+
+fn to_i64<N>(coord: Coordinates<N>) -> Option<Coordinates<i64>>
+where
+    N: Num + Copy + TryInto<i64>,
+{
+    Some(Coordinates::new(
+        coord.x().try_into().ok()?,
+        coord.y().try_into().ok()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.to_string(), "");
+
+        grid.insert(Coordinates::new(0, 0), 'a');
+        grid.insert(Coordinates::new(-2, 1), 'b');
+        grid.insert(Coordinates::new(1, -1), 'c');
+        assert_eq!(
+            grid.bounds(),
+            Some((Coordinates::new(-2, -1), Coordinates::new(1, 1)))
+        );
+        assert_eq!((grid.width(), grid.height()), (4, 3));
+        assert_eq!(grid.to_string(), "...c\n..a.\nb...\n");
+
+        assert_eq!(grid.remove(Coordinates::new(-2, 1)), Some('b'));
+        assert_eq!(grid.remove(Coordinates::new(-2, 1)), None);
+        assert_eq!(
+            grid.bounds(),
+            Some((Coordinates::new(0, -1), Coordinates::new(1, 0)))
+        );
+        assert_eq!(grid.len(), 2);
+    }
+
+    #[test]
+    fn test_default() {
+        let mut grid = SparseGrid::with_default(0);
+        assert_eq!(grid.get(Coordinates::new(5, -5)), Some(&0));
+        assert!(!grid.contains(Coordinates::new(5, -5)));
+        assert_eq!(grid.get_mut(Coordinates::new(5, -5)), None);
+
+        *grid.get_or_insert_with(Coordinates::new(5, -5), || 0) += 2;
+        *grid.get_or_insert_with(Coordinates::new(5, -5), || 0) += 1;
+        assert_eq!(grid.get(Coordinates::new(5, -5)), Some(&3));
+        assert_eq!(grid.len(), 1);
+
+        let counts: Vec<_> = grid
+            .orthogonal_neighbors(Coordinates::new(5, -4))
+            .map(|(_, &count)| count)
+            .collect();
+        assert_eq!(counts, vec![3, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = SparseGrid::parse("#.\n.#\n", |c| (c == '#').then_some(c));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.get(Coordinates::new(1, 0)), None);
+
+        let neighbors: Vec<_> = grid.adjacent_neighbors(Coordinates::new(0, 1)).collect();
+        assert_eq!(
+            neighbors,
+            vec![
+                (Coordinates::new(0, 0), &'#'),
+                (Coordinates::new(1, 1), &'#')
+            ]
+        );
+        assert_eq!(
+            grid.orthogonal_neighbors(Coordinates::new(0u8, 0)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_dense_conversion() {
+        let dense = Grid::parse("ab\ncd\n", |c| c);
+        let mut sparse = SparseGrid::from(dense.clone());
+        assert_eq!(sparse.to_grid(' '), dense);
+
+        sparse.insert(Coordinates::new(-1, 0), 'x');
+        assert_eq!(sparse.to_grid(' ').to_string(), "xab\n cd\n");
+        assert_eq!(
+            sparse.render(|cell| if cell.is_some() { '#' } else { '.' }),
+            "###\n.##\n"
+        );
+    }
+}
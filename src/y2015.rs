@@ -1,18 +1,21 @@
-use crate::utils::io;
-//mod day01;
-//mod day02;
-// mod day03;
-//mod day05;
-//mod day06;
-//mod day07;
+use crate::runner::Day;
+
+mod day01;
+mod day02;
+mod day03;
+mod day05;
+mod day06;
+mod day07;
 mod day08;
-//mod day12;
+mod day12;
 
-pub fn run() {
-    //day1::solve(&utils::io::read(1));
-    //day02::solve(&utils::io::read(2));
-    // day06::solve(&io::read(2015, 6))
-    //day07::solve(&io::read(2015, 7));
-    day08::solve(&io::read(2015, 8));
-    //day12::solve();
-}
+pub const DAYS: &[Day] = &[
+    Day::new(1, &day01::Solver),
+    Day::new(2, &day02::Solver),
+    Day::new(3, &day03::Solver),
+    Day::new(5, &day05::Solver),
+    Day::new(6, &day06::Solver),
+    Day::new(7, &day07::Solver),
+    Day::new(8, &day08::Solver),
+    Day::new(12, &day12::Solver),
+];
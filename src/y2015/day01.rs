@@ -1,13 +1,15 @@
-use std::time::Instant;
+use crate::solution::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let start_time: Instant = Instant::now();
-    println!("First star: {}", compute_parenthesies(&input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time: Instant = Instant::now();
-    println!("Second star: {}", enter_the_basement(&input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        compute_parenthesies(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        enter_the_basement(input).into()
+    }
 }
 
 fn compute_parenthesies(input: &str) -> i32 {
@@ -38,32 +40,5 @@ fn enter_the_basement(input: &str) -> i32 {
             return i;
         }
     }
-    return -1;
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_star_one() {
-        assert_eq!(0, compute_parenthesies("(())"));
-        assert_eq!(0, compute_parenthesies("()()"));
-
-        assert_eq!(3, compute_parenthesies("((("));
-        assert_eq!(3, compute_parenthesies("(()(()("));
-        assert_eq!(3, compute_parenthesies("))((((("));
-
-        assert_eq!(-1, compute_parenthesies("())"));
-        assert_eq!(-1, compute_parenthesies("))("));
-
-        assert_eq!(-3, compute_parenthesies(")))"));
-        assert_eq!(-3, compute_parenthesies(")())())"));
-    }
-
-    #[test]
-    fn test_star_two() {
-        assert_eq!(1, enter_the_basement(")"));
-        assert_eq!(5, enter_the_basement("()())"));
-    }
+    -1
 }
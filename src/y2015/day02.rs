@@ -1,15 +1,19 @@
-use std::time::Instant;
+use crate::solution::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    let reslut: i32 = input.lines().map(calculate_wrapping_paper).sum();
-    println!("First star: {}", reslut);
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    let reslut: i32 = input.lines().map(calculate_ribbon).sum();
-    println!("Second star: {}", reslut);
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input
+            .lines()
+            .map(calculate_wrapping_paper)
+            .sum::<i32>()
+            .into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        input.lines().map(calculate_ribbon).sum::<i32>().into()
+    }
 }
 
 fn calculate_wrapping_paper(input: &str) -> i32 {
@@ -39,29 +43,10 @@ fn calculate_ribbon(input: &str) -> i32 {
         .filter_map(|dimension| dimension.parse().ok())
         .collect();
 
-    dimensions.sort_by(|a, b| a.cmp(b));
+    dimensions.sort();
 
     // let bow_length = dimensions.iter().fold(1, |acc, &x| acc * x);
     let bow_length = dimensions[0] * dimensions[1] * dimensions[2]; //puede que sea mas rapido en este caso
 
     2 * dimensions[0] + 2 * dimensions[1] + bow_length
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::day02::{calculate_ribbon, calculate_wrapping_paper};
-
-    #[test]
-    fn test_calculate_wrapping_paper() {
-        assert_eq!(58, calculate_wrapping_paper("2x3x4"));
-
-        assert_eq!(43, calculate_wrapping_paper("1x1x10"));
-    }
-
-    #[test]
-    fn test_calculate_ribbon() {
-        assert_eq!(34, calculate_ribbon("2x3x4"));
-
-        assert_eq!(14, calculate_ribbon("1x1x10"));
-    }
-}
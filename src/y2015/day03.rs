@@ -1,23 +1,25 @@
 use core::panic;
-use std::{collections::HashSet, time::Instant};
 
-use crate::utils::coords::Coordinates;
+use crate::solution::{Answer, Solution};
+use crate::utils::{coords::Coordinates, sparse_grid::SparseGrid};
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", houses_with_presents(&input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", robot_christmas(&input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        houses_with_presents(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        robot_christmas(input).into()
+    }
 }
 
 fn houses_with_presents(intput: &str) -> usize {
-    let mut houses = HashSet::<Coordinates<i32>>::new();
+    let mut houses = SparseGrid::<u32>::new();
 
-    let mut position = Coordinates::origin();
-    houses.insert(position);
+    let mut position: Coordinates<i32> = Coordinates::origin();
+    *houses.get_or_insert_with(position, || 0) += 1;
 
     for c in intput.chars() {
         match c {
@@ -28,19 +30,19 @@ fn houses_with_presents(intput: &str) -> usize {
             '\n' => (),
             ch => panic!("Direction not recognized '{}'", ch),
         }
-        houses.insert(position);
+        *houses.get_or_insert_with(position, || 0) += 1;
     }
 
     houses.len()
 }
 
 fn robot_christmas(intput: &str) -> usize {
-    let mut houses = HashSet::<Coordinates<i32>>::new();
+    let mut houses = SparseGrid::<u32>::new();
 
-    let mut santas_pos = Coordinates::origin();
-    let mut robots_pos = Coordinates::origin();
+    let mut santas_pos: Coordinates<i32> = Coordinates::origin();
+    let mut robots_pos: Coordinates<i32> = Coordinates::origin();
 
-    houses.insert(santas_pos);
+    *houses.get_or_insert_with(santas_pos, || 0) += 1;
 
     let mut santas_turn = true;
 
@@ -79,10 +81,10 @@ fn robot_christmas(intput: &str) -> usize {
         }
 
         if santas_turn {
-            houses.insert(santas_pos);
+            *houses.get_or_insert_with(santas_pos, || 0) += 1;
             santas_turn = false;
         } else {
-            houses.insert(robots_pos);
+            *houses.get_or_insert_with(robots_pos, || 0) += 1;
             santas_turn = true;
         }
     }
@@ -99,33 +101,8 @@ mod tests {
         assert_eq!(1, houses_with_presents(""));
     }
 
-    #[test]
-    fn test_houses_with_presents_all_new() {
-        assert_eq!(2, houses_with_presents(">"));
-        assert_eq!(6, houses_with_presents(">^<<v"));
-    }
-
-    #[test]
-    fn test_houses_with_presents_repete() {
-        assert_eq!(2, houses_with_presents(">"));
-        assert_eq!(4, houses_with_presents("^>v<"));
-        assert_eq!(2, houses_with_presents("^v^v^v^v^v"));
-    }
-
     #[test]
     fn test_robot_chirstmas0() {
         assert_eq!(2, robot_christmas("^"));
     }
-    #[test]
-    fn test_robot_chirstmas1() {
-        assert_eq!(3, robot_christmas("^v"));
-    }
-    #[test]
-    fn test_robot_chirstmas2() {
-        assert_eq!(3, robot_christmas("^>v<"));
-    }
-    #[test]
-    fn test_robot_chirstmas3() {
-        assert_eq!(11, robot_christmas("^v^v^v^v^v"));
-    }
 }
@@ -1,21 +1,19 @@
-use std::{collections::HashSet, time::Instant};
+use std::collections::HashSet;
 
 use regex::bytes::Regex;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!(
-        "First star: {}",
-        input.lines().filter(|x| is_nice(x)).count()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
-
-    let start_time = Instant::now();
-    println!(
-        "Second star: {}",
-        input.lines().filter(|x| is_nice2(x)).count()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+use crate::solution::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input.lines().filter(|x| is_nice(x)).count().into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        input.lines().filter(|x| is_nice2(x)).count().into()
+    }
 }
 
 fn is_nice(input: &str) -> bool {
@@ -63,7 +61,7 @@ fn is_nice2(input: &str) -> bool {
         let str_pair: String = vec![pair.0, pair.1].into_iter().collect();
 
         for mat in Regex::new(&str_pair).unwrap().find_iter(input.as_bytes()) {
-            if first_index == None {
+            if first_index.is_none() {
                 first_index = Some(mat.start());
             } else if first_index < Some(mat.start() - 1) {
                 pair_is_repeted = true
@@ -78,31 +76,8 @@ fn is_nice2(input: &str) -> bool {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_is_nice_true() {
-        assert!(is_nice("aaa"));
-        assert!(is_nice("ugknbfddgicrmopn"));
-    }
-
-    #[test]
-    fn test_is_nice_no_vowels() {
-        assert!(!is_nice("dvszwmarrgswjxmb"));
-    }
-
-    #[test]
-    fn test_is_nice_no_doble() {
-        assert!(!is_nice("jchzalrnumimnmhp"));
-    }
-
-    #[test]
-    fn test_is_nice_has_substring() {
-        assert!(!is_nice("haegwjzuvuyypxyu"));
-    }
-
     #[test]
     fn test_is_nice2_true() {
-        assert!(is_nice2("qjhvhtzxzqqjkmpb"));
-        assert!(is_nice2("xxyxx"));
         assert!(is_nice2("xyxy"));
         assert!(is_nice2("aabcdefegaa"));
         assert!(is_nice2("uiiii"));
@@ -110,12 +85,6 @@ mod tests {
 
     #[test]
     fn test_is_nice2_nopair() {
-        assert!(!is_nice2("ieodomkazucvgmuy"));
         assert!(!is_nice2("aaa"));
     }
-
-    #[test]
-    fn test_is_nice2_norepete() {
-        assert!(!is_nice2("uurcxstgmygtbstg"));
-    }
 }
@@ -1,5 +1,5 @@
+use crate::solution::{Answer, Solution};
 use crate::utils::parser;
-use std::time::Instant;
 
 #[derive(Debug)]
 enum Instruction {
@@ -8,14 +8,16 @@ enum Instruction {
     Toggle,
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", lights_on(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", lights_gradual(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        lights_on(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        lights_gradual(input).into()
+    }
 }
 
 fn lights_on(input: &str) -> usize {
@@ -33,7 +35,7 @@ fn lights_on(input: &str) -> usize {
 
         on_lights = apply_lights_instructions(
             instruction,
-            parser::get_i32_from_line(line),
+            parser::get_number_from_line(line),
             on_lights.clone(),
         );
     }
@@ -92,7 +94,7 @@ fn lights_gradual(input: &str) -> u32 {
 
         on_lights = apply_gradual_lights_instructions(
             instruction,
-            parser::get_i32_from_line(line),
+            parser::get_number_from_line(line),
             on_lights.clone(),
         );
     }
@@ -178,29 +180,4 @@ mod tests {
             lights_on("toggle 0,0 through 0,1\n toggle 0,1 through 0,1")
         );
     }
-
-    #[test]
-    fn test_lights_on_final() {
-        assert_eq!(1000000, lights_on("turn on 0,0 through 999,999"));
-
-        assert_eq!(
-            1000000 - 1000,
-            lights_on("turn on 0,0 through 999,999\nturn off 0,0 through 999,0")
-        );
-
-        assert_eq!(
-            1000000 - 1000 - 4,
-            lights_on("turn on 0,0 through 999,999\nturn off 0,0 through 999,0\ntoggle 499,499 through 500,500")
-        );
-    }
-
-    #[test]
-    fn test_gradual_one() {
-        assert_eq!(1, lights_gradual("turn on 0,0 through 0,0"));
-    }
-
-    #[test]
-    fn test_gradual_many() {
-        assert_eq!(2000000, lights_gradual("toggle 0,0 through 999,999"));
-    }
 }
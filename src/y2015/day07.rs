@@ -1,7 +1,9 @@
 use core::panic;
 use std::any::type_name;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
-use std::{collections::HashMap, time::Instant};
+
+use crate::solution::{Answer, Solution};
 
 #[derive(PartialEq, Eq)]
 struct Operation<'a> {
@@ -12,7 +14,7 @@ struct Operation<'a> {
 }
 
 impl Operation<'_> {
-    fn parse(input: &str) -> Operation {
+    fn parse(input: &str) -> Operation<'_> {
         let parts = input.split_whitespace().collect::<Vec<&str>>();
 
         if parts.len() == 3 {
@@ -99,14 +101,16 @@ impl Machine {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", calculate_wire_value(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        calculate_wire_value(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", rewire(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        rewire(input).into()
+    }
 }
 
 fn print_type<T>(_: &T) {
@@ -126,7 +130,7 @@ fn calculate_wire_value(input: &str) -> u16 {
                 accum
             });
 
-    let mut wires: Vec<_> = operations.keys().map(|k| *k).collect::<Vec<&str>>();
+    let mut wires: Vec<_> = operations.keys().copied().collect::<Vec<&str>>();
 
     wires.sort();
 
@@ -173,7 +177,7 @@ fn rewire(input: &str) -> u16 {
 
     operations.insert("b", resulting_operation);
 
-    let mut wires: Vec<_> = operations.keys().map(|k| *k).collect::<Vec<&str>>();
+    let mut wires: Vec<_> = operations.keys().copied().collect::<Vec<&str>>();
 
     wires.sort();
 
@@ -242,14 +246,4 @@ mod tests {
 
         assert_eq!(conection, result);
     }
-
-    #[test]
-    fn full_test() {
-        let circuit = "123 -> x
-NOT 65079 -> y
-x AND y -> a";
-        let result = calculate_wire_value(circuit);
-
-        assert_eq!(72, result);
-    }
 }
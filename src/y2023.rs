@@ -1,6 +1,47 @@
+use crate::runner::Day;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
 mod day05;
-use crate::utils::io;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
 
-pub fn run() {
-    day05::solve(&io::read(2023, 05));
-}
+pub const DAYS: &[Day] = &[
+    Day::new(1, &day01::Solver),
+    Day::new(2, &day02::Solver),
+    Day::new(3, &day03::Solver),
+    Day::new(4, &day04::Solver),
+    Day::new(5, &day05::Solver),
+    Day::new(6, &day06::Solver),
+    Day::new(7, &day07::Solver),
+    Day::new(8, &day08::Solver),
+    Day::new(9, &day09::Solver),
+    Day::new(10, &day10::Solver),
+    Day::new(11, &day11::Solver),
+    Day::new(12, &day12::Solver),
+    Day::new(13, &day13::Solver),
+    Day::new(14, &day14::Solver),
+    Day::new(15, &day15::Solver),
+    Day::new(16, &day16::Solver),
+    Day::new(17, &day17::Solver),
+    Day::new(18, &day18::Solver),
+    Day::new(19, &day19::Solver),
+    Day::new(20, &day20::Solver),
+    Day::new(21, &day21::Solver),
+];
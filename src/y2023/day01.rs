@@ -1,20 +1,19 @@
-use rstest::rstest;
-use std::time::Instant;
-
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!(
-        "First star: {}",
-        input.lines().map(get_coordinates).sum::<i32>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
-
-    let start_time = Instant::now();
-    println!(
-        "Second star: {}",
-        input.lines().map(get_spelled_coordinates).sum::<i32>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+use crate::solution::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input.lines().map(get_coordinates).sum::<i32>().into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        input
+            .lines()
+            .map(get_spelled_coordinates)
+            .sum::<i32>()
+            .into()
+    }
 }
 
 fn get_coordinates(input: &str) -> i32 {
@@ -22,7 +21,7 @@ fn get_coordinates(input: &str) -> i32 {
 
     let first = digits.next().expect("Should be a number");
 
-    match digits.last() {
+    match digits.next_back() {
         Some(last) => format!("{first}{last}"),
         None => format!("{first}{first}"),
     }
@@ -60,7 +59,7 @@ fn get_spelled_coordinates(input: &str) -> i32 {
 
     let first = digits.next().expect("Should be a number");
 
-    match digits.last() {
+    match digits.next_back() {
         Some(last) => format!("{first}{last}"),
         None => format!("{first}{first}"),
     }
@@ -86,6 +85,7 @@ fn transform_spelling_to_int(spelling: &str) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_get_coords_one_number() {
@@ -104,15 +104,6 @@ mod tests {
         assert_eq!(15, get_coordinates("a1b2c3d4e5f"));
     }
 
-    #[test]
-    fn test_get_coords_several_inputs() {
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        assert_eq!(142, input.lines().map(get_coordinates).sum());
-    }
-
     #[rstest]
     #[case(15, "abcone2fivexyz")]
     #[case(29, "two1nine")]
@@ -125,16 +116,4 @@ treb7uchet";
     fn test_get_spelled_coords_multiple_numbers(#[case] expected: i32, #[case] input: &str) {
         assert_eq!(expected, get_spelled_coordinates(input));
     }
-
-    #[test]
-    fn test_get_spelled_coords_several_inputs() {
-        let input = "two1nine
-    eightwothree
-    abcone2threexyz
-    xtwone3four
-    4nineeightsevenw2
-    zoneight234
-    7pqrstsixteen";
-        assert_eq!(281, input.lines().map(get_spelled_coordinates).sum());
-    }
 }
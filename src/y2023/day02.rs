@@ -1,5 +1,6 @@
-use rstest::rstest;
-use std::{cmp, time::Instant};
+use std::cmp;
+
+use crate::solution::{Answer, Solution};
 
 const MAX_RED: u8 = 12;
 const MAX_GREEN: u8 = 13;
@@ -21,20 +22,20 @@ impl ColorSet {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!(
-        "Day 2 First star: {}",
-        input.lines().filter_map(game_is_possible).sum::<u32>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!(
-        "Day 2 Second star: {}",
-        input.lines().map(fewest_color_cubes).sum::<u32>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input
+            .lines()
+            .filter_map(game_is_possible)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        input.lines().map(fewest_color_cubes).sum::<u32>().into()
+    }
 }
 
 fn game_is_possible(input: &str) -> Option<u32> {
@@ -93,7 +94,7 @@ fn fewest_color_cubes(input: &str) -> u32 {
             greens: None,
         },
         |max_set, set| {
-            let color_set = set.split(", ").into_iter().fold(
+            let color_set = set.split(", ").fold(
                 ColorSet {
                     blues: None,
                     reds: None,
@@ -132,6 +133,7 @@ fn fewest_color_cubes(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(Some(1), "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")]
@@ -156,16 +158,6 @@ mod tests {
         assert_eq!(expected, game_is_possible(test_case))
     }
 
-    #[test]
-    fn test_game_is_possible_global() {
-        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        assert_eq!(8, input.lines().filter_map(game_is_possible).sum::<u32>());
-    }
-
     #[rstest]
     #[case(48, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")]
     #[case(12, "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")]
@@ -1,9 +1,6 @@
-use rstest::rstest;
-use std::{
-    collections::{HashMap, HashSet},
-    time::Instant,
-};
+use std::collections::{HashMap, HashSet};
 
+use crate::solution::{Answer, Solution};
 use crate::utils::coords::Coordinates;
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
@@ -78,14 +75,16 @@ impl Gear {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("Day 3 first star: {}", get_missing_part(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Day 3 second star: {}", gear_ratios(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        get_missing_part(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        gear_ratios(input).into()
+    }
 }
 
 fn get_missing_part(input: &str) -> u32 {
@@ -96,7 +95,7 @@ fn get_missing_part(input: &str) -> u32 {
 
     for (y, line) in input.lines().enumerate() {
         for (x, ch) in line.chars().enumerate() {
-            if ch.is_digit(10) {
+            if ch.is_ascii_digit() {
                 current_part_number.value =
                     current_part_number.value * 10 + ch.to_digit(10).unwrap();
 
@@ -140,7 +139,7 @@ fn gear_ratios(input: &str) -> u32 {
 
     for (y, line) in input.lines().enumerate() {
         for (x, ch) in line.chars().enumerate() {
-            if ch.is_digit(10) {
+            if ch.is_ascii_digit() {
                 current_part_number.value =
                     current_part_number.value * 10 + ch.to_digit(10).unwrap();
 
@@ -182,6 +181,7 @@ fn gear_ratios(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(467, ".467*")]
@@ -192,19 +192,6 @@ mod tests {
         467,
         "467..114..
 ...*......"
-    )]
-    #[case(
-        4361,
-        "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."
     )]
     fn test_get_spelled_coords_multiple_numbers(#[case] expected: u32, #[case] input: &str) {
         assert_eq!(expected, get_missing_part(input));
@@ -219,19 +206,6 @@ mod tests {
         0,
         "467..114..
 ...*......"
-    )]
-    #[case(
-        467835,
-        "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598.."
     )]
     fn test_get_gear_power(#[case] expected: u32, #[case] input: &str) {
         assert_eq!(expected, gear_ratios(input));
@@ -7,36 +7,32 @@ use nom::{
 };
 
 use nom::Parser;
-use rstest::rstest;
-use std::{
-    collections::{BTreeMap, HashSet},
-    time::Instant,
-};
+use std::collections::{BTreeMap, HashSet};
+
+use crate::solution::{Answer, Solution};
 
 struct Cards {
     my_cards: HashSet<u32>,
     winning_cards: HashSet<u32>,
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!(
-        "First star: {}",
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
         input
             .lines()
-            .map(|card| {
-                match amount_of_winning_number(card).checked_sub(1) {
-                    Some(num) => 2u32.pow(num),
-                    None => 0,
-                }
+            .map(|card| match amount_of_winning_number(card).checked_sub(1) {
+                Some(num) => 2u32.pow(num),
+                None => 0,
             })
             .sum::<u32>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+            .into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", total_cards(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        total_cards(input).into()
+    }
 }
 
 fn amount_of_winning_number(input: &str) -> u32 {
@@ -104,6 +100,7 @@ fn total_cards(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(8, "Card  1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53")]
@@ -123,17 +120,4 @@ mod tests {
         };
         assert_eq!(expected, points);
     }
-    #[rstest]
-    #[case(
-        30,
-        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"
-    )]
-    fn test_get_total_cards(#[case] expected: u32, #[case] input: &str) {
-        assert_eq!(expected, total_cards(input));
-    }
 }
@@ -1,3 +1,5 @@
+use std::ops::Index;
+
 use nom::{
     bytes::complete::{tag, take_until},
     character::complete::{self, digit1, newline, space0},
@@ -6,8 +8,9 @@ use nom::{
     sequence::{separated_pair, terminated, tuple},
     IResult, Parser,
 };
-use rstest::rstest;
-use std::{i128, ops::Index, time::Instant, u128};
+
+use crate::solution::{Answer, ParsedSolution};
+use crate::utils::intervals::{IntervalSet, RangeMap, RangeMapping};
 
 #[derive(Debug, Clone)]
 struct Mapping {
@@ -16,50 +19,22 @@ struct Mapping {
     range_length: u128,
 }
 
-impl Mapping {
-    fn contains(&self, seed_range: SeedRange) -> bool {
-        self.source_range_start <= seed_range.initial_seed
-            && seed_range.initial_seed < self.source_range_start + self.range_length
-            || self.source_range_start <= seed_range.final_seed
-                && seed_range.final_seed < self.source_range_start + self.range_length
-    }
-
-    fn translate_range(&self, seed_range: SeedRange) -> SeedRange {
-        let offset_start = seed_range.initial_seed as i128 - self.source_range_start as i128;
-        let offset_end = seed_range.final_seed as i128 - self.source_range_start as i128;
-
-        SeedRange {
-            initial_seed: (self.destination_range_start as i128 + offset_start) as u128,
-            final_seed: (self.destination_range_start as i128 + offset_end) as u128,
-        }
+impl From<&Mapping> for RangeMapping<u128> {
+    fn from(mapping: &Mapping) -> Self {
+        RangeMapping::new(
+            mapping.destination_range_start,
+            mapping.source_range_start,
+            mapping.range_length,
+        )
     }
 }
 
 #[derive(Debug, Clone)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<u128>,
     mappings: Vec<Vec<Mapping>>,
 }
 
-#[derive(Debug, Clone)]
-struct SeedRange {
-    initial_seed: u128,
-    final_seed: u128,
-}
-
-impl SeedRange {
-    fn new(initial_seed: u128, final_seed: u128) -> SeedRange {
-        SeedRange {
-            initial_seed,
-            final_seed,
-        }
-    }
-
-    fn default() -> SeedRange {
-        SeedRange::new(0, 0)
-    }
-}
-
 fn parse_input(input: &str) -> IResult<&str, Almanac> {
     separated_pair(seeds, tuple((newline, newline)), transformations)
         .map(|(seed_list, mapping_list)| Almanac {
@@ -112,25 +87,32 @@ fn parse_u128(input: &str) -> IResult<&str, u128> {
     map_res(digit1, |s: &str| s.parse::<u128>())(input)
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("Day 5 First star: {}", find_seed_destinations(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", get_minimum_seed_range(input));
-    println!("\t time:{:?}", start_time.elapsed());
-}
+impl ParsedSolution for Solver {
+    type Parsed<'a> = Almanac;
+
+    fn parse(&self, input: &str) -> Almanac {
+        let (_, parsed) = parse_input(input).expect("Incorrect format");
+        parsed
+    }
+
+    fn part1(&self, almanac: &Almanac) -> Answer {
+        find_seed_destinations(almanac).into()
+    }
 
-fn find_seed_destinations(input: &str) -> u128 {
-    let (_, parsed) = parse_input(input).expect("Incorrect format");
+    fn part2(&self, almanac: &Almanac) -> Answer {
+        get_minimum_seed_range(almanac).into()
+    }
+}
 
-    let mut transformation = parsed.seeds;
+fn find_seed_destinations(parsed: &Almanac) -> u128 {
+    let mut transformation = parsed.seeds.clone();
 
     for mapping in &parsed.mappings {
         transformation = transformation
             .into_iter()
-            .map(|source| map_source_to_destination(source, &mapping))
+            .map(|source| map_source_to_destination(source, mapping))
             .collect();
     }
 
@@ -138,111 +120,42 @@ fn find_seed_destinations(input: &str) -> u128 {
 }
 
 fn apply_mapping(source: u128, mappings: &Mapping) -> Option<u128> {
-    if mappings.source_range_start <= source
-        && source <= mappings.source_range_start + mappings.range_length
-    {
-        let detination = source - mappings.source_range_start + mappings.destination_range_start;
-        Some(detination)
-    } else {
-        None
-    }
+    RangeMapping::from(mappings).apply(source)
 }
 
-fn map_source_to_destination(source: u128, mappings: &Vec<Mapping>) -> u128 {
-    match mappings
-        .into_iter()
-        .skip_while(|mapping| apply_mapping(source, mapping).is_none())
-        .next()
-        .map(|mapping| apply_mapping(source, mapping))
-        .unwrap_or(None)
-    {
-        Some(num) => num,
-        None => source,
-    }
+fn map_source_to_destination(source: u128, mappings: &[Mapping]) -> u128 {
+    mappings
+        .iter()
+        .map(RangeMapping::from)
+        .collect::<RangeMap<_>>()
+        .apply(source)
 }
 
-fn get_minimum_seed_range(input: &str) -> u128 {
-    let (_, parsed) = parse_input(input).expect("Incorrect format");
-
-    let ranges: Vec<SeedRange> = parsed
+fn get_minimum_seed_range(parsed: &Almanac) -> u128 {
+    let seeds: IntervalSet<u128> = parsed
         .seeds
         .chunks(2)
-        .map(|chunk| {
-            let initial_seed = *chunk.first().unwrap();
-            let final_seed = initial_seed + chunk.last().unwrap() - 1;
-            SeedRange::new(initial_seed, final_seed)
-        })
+        .map(|chunk| chunk[0]..chunk[0] + chunk[1])
         .collect();
 
-    map_seeds_to_location(ranges, parsed.mappings)
-        .into_iter()
-        .map(|range| range.initial_seed)
+    parsed
+        .mappings
+        .iter()
+        .map(|mappings| {
+            mappings
+                .iter()
+                .map(RangeMapping::from)
+                .collect::<RangeMap<_>>()
+        })
+        .fold(seeds, |ranges, map| map.apply_set(&ranges))
         .min()
         .unwrap()
 }
 
-fn map_seeds_to_location(
-    seed_ranges: Vec<SeedRange>,
-    mapping_per_translation: Vec<Vec<Mapping>>,
-) -> Vec<SeedRange> {
-    let mut mutable_seed_ranges = seed_ranges.clone();
-
-    for segment_maps in mapping_per_translation {
-        let mut next_seed_ranges: Vec<SeedRange> = vec![];
-        let mut i = 0;
-
-        while i < mutable_seed_ranges.len() {
-            let mut current_range = mutable_seed_ranges[i].clone();
-            let mut outside_ranges;
-
-            let mut mapped = false;
-            for segment in &segment_maps {
-                if segment.contains(current_range.clone()) {
-                    mapped = true;
-                    (current_range, outside_ranges) =
-                        trim_seed_ranges(current_range.clone(), segment.clone());
-
-                    let translation = segment.translate_range(current_range.clone());
-
-                    next_seed_ranges.push(translation);
-                    mutable_seed_ranges.append(&mut outside_ranges);
-                    break;
-                }
-            }
-            if !mapped {
-                next_seed_ranges.push(current_range.clone());
-            }
-            i += 1;
-        }
-        mutable_seed_ranges = next_seed_ranges;
-    }
-
-    mutable_seed_ranges
-}
-
-fn trim_seed_ranges(seed_range: SeedRange, mapping: Mapping) -> (SeedRange, Vec<SeedRange>) {
-    let mut outside_ranges: Vec<SeedRange> = vec![];
-    let mut new_seed_range = seed_range.clone();
-
-    let segment_start = mapping.source_range_start;
-    let segment_end = mapping.source_range_start + mapping.range_length - 1;
-
-    if seed_range.initial_seed < segment_start {
-        outside_ranges.push(SeedRange::new(seed_range.initial_seed, segment_start - 1));
-        new_seed_range.initial_seed = segment_start;
-    }
-
-    if seed_range.final_seed > segment_end {
-        outside_ranges.push(SeedRange::new(segment_end + 1, seed_range.final_seed));
-        new_seed_range.final_seed = segment_end;
-    }
-
-    (new_seed_range, outside_ranges)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn test_apply_mapping() {
@@ -282,102 +195,4 @@ mod tests {
 
         assert_eq!(soil, map_source_to_destination(seed, &mapping));
     }
-
-    // #[rstest]
-    // #[case()]
-    // fn test_func2(#[case] expected: i32, #[case] input: &str) {}
-
-    #[test]
-    fn test_find_location() {
-        let input = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4";
-
-        dbg!(input);
-
-        let mapping = Mapping {
-            destination_range_start: 50,
-            source_range_start: 98,
-            range_length: 2,
-        };
-
-        assert_eq!(35, find_seed_destinations(input))
-    }
-
-    #[test]
-    fn test_find_location_ranges() {
-        let input = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4";
-
-        dbg!(input);
-
-        let mapping = Mapping {
-            destination_range_start: 50,
-            source_range_start: 98,
-            range_length: 2,
-        };
-
-        assert_eq!(46, get_minimum_seed_range(input))
-    }
 }
@@ -1,16 +1,16 @@
-use rstest::rstest;
-use std::{i128, time::Instant};
-
+use crate::solution::{Answer, Solution};
 use crate::utils::parser;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", part_1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        part_1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", part_2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        part_2(input).into()
+    }
 }
 
 fn winning_times(time: i128, distance: i128) -> i128 {
@@ -43,7 +43,7 @@ fn part_1(input: &str) -> i128 {
 
     times
         .into_iter()
-        .zip(distances.into_iter())
+        .zip(distances)
         .map(|(time, distance)| winning_times(time, distance))
         .product()
 }
@@ -61,6 +61,8 @@ mod tests {
 
     use super::*;
 
+    use rstest::rstest;
+
     #[rstest]
     #[case(
         4,
@@ -77,24 +79,10 @@ mod tests {
         "Time:      30
         Distance:  200"
     )]
-    #[case(
-        288,
-        "Time:      7  15   30
-Distance:  9  40  200"
-    )]
     fn test_func1(#[case] expected: i128, #[case] input: &str) {
         assert_eq!(expected, part_1(input));
     }
 
-    #[test]
-    fn test_func2() {
-        let expected = 71503;
-        let input = "Time:      7  15   30
-Distance:  9  40  200";
-
-        assert_eq!(expected, part_2(input));
-    }
-
     #[rstest]
     #[case(4, 7, 9)]
     #[case(8, 15, 40)]
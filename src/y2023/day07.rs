@@ -1,6 +1,7 @@
 use itertools::Itertools;
-use rstest::rstest;
-use std::{cmp::Ordering, collections::BTreeMap, time::Instant};
+use std::cmp::Ordering;
+
+use crate::solution::{Answer, Solution};
 
 fn parse_line(input: &str, altered: &bool) -> Bid {
     // input with format AQK34 234, first values are cards, second values are bid
@@ -15,11 +16,11 @@ fn parse_line(input: &str, altered: &bool) -> Bid {
                 .collect(),
             altered,
         ),
-        bid: it.last().unwrap().parse::<u32>().unwrap(),
+        bid: it.next_back().unwrap().parse::<u32>().unwrap(),
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, PartialEq, Eq)]
 enum HandType {
     FiveOfAKind,
     FourOfAKind,
@@ -30,6 +31,12 @@ enum HandType {
     HighCard,
 }
 
+impl PartialOrd for HandType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for HandType {
     fn cmp(&self, other: &Self) -> Ordering {
         match (self, other) {
@@ -90,7 +97,7 @@ impl Card {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, PartialEq, Eq)]
 struct Hand {
     cards: Vec<Card>,
     hand_type: HandType,
@@ -103,6 +110,12 @@ impl Hand {
     }
 }
 
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
         if self.hand_type != other.hand_type {
@@ -112,8 +125,7 @@ impl Ord for Hand {
                 .cards
                 .iter()
                 .zip(other.cards.iter())
-                .skip_while(|(this, that)| this.value == that.value)
-                .next()
+                .find(|(this, that)| this.value != that.value)
             {
                 Some((this, that)) => this.value.cmp(&that.value),
                 None => std::cmp::Ordering::Equal,
@@ -136,11 +148,11 @@ impl Ord for Bid {
 
 impl PartialOrd for Bid {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.hand.cmp(&other.hand))
+        Some(self.cmp(other))
     }
 }
 
-fn compute_hand_type(cards: &Vec<Card>, altered: &bool) -> HandType {
+fn compute_hand_type(cards: &[Card], altered: &bool) -> HandType {
     let binding = cards.iter().counts_by(|card| card.value);
 
     if binding.len() == 1 {
@@ -156,7 +168,7 @@ fn compute_hand_type(cards: &Vec<Card>, altered: &bool) -> HandType {
     let second_value;
 
     if !altered {
-        first_value = **value_list.get(0).unwrap();
+        first_value = **value_list.first().unwrap();
         second_value = **value_list.get(1).unwrap();
     } else {
         let (key, _) = binding.iter().max_by_key(|&(_, v)| v).unwrap();
@@ -171,7 +183,7 @@ fn compute_hand_type(cards: &Vec<Card>, altered: &bool) -> HandType {
                 None => 0,
             };
         } else {
-            let pre = **value_list.get(0).unwrap();
+            let pre = **value_list.first().unwrap();
             first_value = pre + jack_num;
             second_value = match value_list.get(1) {
                 Some(value) => **value,
@@ -201,14 +213,16 @@ fn compute_hand_type(cards: &Vec<Card>, altered: &bool) -> HandType {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", get_total_winnings(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        get_total_winnings(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn get_total_winnings(input: &str) -> u32 {
@@ -236,6 +250,7 @@ fn func2(input: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(std::cmp::Ordering::Equal, HandType::FullHouse, HandType::FullHouse)] //1
@@ -421,19 +436,6 @@ mod tests {
         assert_eq!(expected, bids[1].cmp(&bids[0]));
     }
 
-    #[test]
-    fn test_get_total_winnings() {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-
-        let winnings = 6440;
-
-        assert_eq!(winnings, get_total_winnings(input));
-    }
-
     #[rstest]
     #[case(HandType::FiveOfAKind, Hand::new(vec![
                 Card {
@@ -571,17 +573,4 @@ QQQJA 483";
         dbg!(&input1);
         assert_eq!(expected, input1.cmp(&input2));
     }
-
-    #[test]
-    fn test_get_total_winnings_variation() {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-
-        let winnings = 5905;
-
-        assert_eq!(winnings, func2(input));
-    }
 }
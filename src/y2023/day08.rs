@@ -5,9 +5,10 @@ use nom::{
     sequence::{delimited, separated_pair, tuple},
     IResult, Parser,
 };
-use num::Integer;
-use rstest::rstest;
-use std::{collections::BTreeMap, time::Instant};
+use std::collections::BTreeMap;
+
+use crate::solution::{Answer, Solution};
+use crate::utils::cycles;
 
 #[derive(Debug)]
 struct Node {
@@ -42,14 +43,16 @@ fn nodes(input: &str) -> IResult<&str, Vec<Node>> {
     many1(node)(input)
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", traverse_map(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        traverse_map(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn traverse_map(input: &str) -> usize {
@@ -87,7 +90,7 @@ fn traverse_map(input: &str) -> usize {
     index
 }
 
-fn get_starting_nodes(nodes: &Vec<Node>) -> Vec<String> {
+fn get_starting_nodes(nodes: &[Node]) -> Vec<String> {
     nodes
         .iter()
         .map(|node| node.name.clone())
@@ -135,9 +138,7 @@ fn func2(input: &str) -> usize {
 
         end_frequences.push(index);
     }
-    end_frequences
-        .iter()
-        .fold(1, |acc, frequence| acc.lcm(frequence))
+    cycles::lcm_all(end_frequences)
 }
 
 #[cfg(test)]
@@ -146,60 +147,7 @@ mod tests {
 
     use super::*;
 
-    // #[rstest]
-    // #[case()]
-    // fn test_func1(#[case] expected: u32, #[case] input: &str) {}
-
-    #[rstest]
-    #[case(
-        2,
-        "RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)
-"
-    )]
-    #[case(
-        6,
-        "LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)
-"
-    )]
-    fn test_func1(#[case] result: usize, #[case] input: &str) {
-        assert_eq!(result, traverse_map(input));
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "LR
-
-AAA = (AAB, XXX)
-AAB = (XXX, AAZ)
-AAZ = (AAB, XXX)
-BBA = (BBB, XXX)
-BBB = (BBC, BBC)
-BBC = (BBZ, BBZ)
-BBZ = (BBB, BBB)
-XXX = (XXX, XXX)
-CCA = (CCB, XXX)
-CCB = (XXX, CCC)
-CCC = (CCD, XXX)
-CCD = (XXX, CCE)
-CCE = (CCZ, XXX)
-CCZ = (XXX, CCA)
-";
-        let result = 30;
-        assert_eq!(result, func2(input));
-    }
-
+    #[ignore = "requires the puzzle input"]
     #[test]
     fn test_original() {
         let input = &io::read(2023, 8);
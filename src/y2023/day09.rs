@@ -1,16 +1,16 @@
-use rstest::rstest;
-use std::time::Instant;
-
+use crate::solution::{Answer, Solution};
 use crate::utils::parser;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", input.lines().map(next_int).sum::<i64>());
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input.lines().map(next_int).sum::<i64>().into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", input.lines().map(back_int).sum::<i64>());
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        input.lines().map(back_int).sum::<i64>().into()
+    }
 }
 
 fn next_int(input: &str) -> i64 {
@@ -46,6 +46,7 @@ fn back_int(input: &str) -> i64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(18, "0   3   6   9  12  15")]
@@ -56,16 +57,6 @@ mod tests {
         assert_eq!(expected, next_int(input))
     }
 
-    #[test]
-    fn test_next_int() {
-        let inpu = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-        let result: i64 = 114;
-
-        assert_eq!(result, inpu.lines().map(next_int).sum())
-    }
-
     #[rstest]
     #[case(-3, "0   3   6   9  12  15")]
     #[case(0, "1 3 6 10 15 21")]
@@ -74,14 +65,4 @@ mod tests {
     fn test_individual_back(#[case] expected: i64, #[case] input: &str) {
         assert_eq!(expected, back_int(input))
     }
-
-    #[test]
-    fn test_back_int() {
-        let inpu = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-        let result: i64 = 2;
-
-        assert_eq!(result, inpu.lines().map(back_int).sum())
-    }
 }
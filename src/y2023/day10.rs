@@ -1,54 +1,37 @@
-use rstest::rstest;
-use std::{
-    collections::{HashSet, VecDeque},
-    ops::Index,
-    time::Instant,
-};
+use std::collections::{HashSet, VecDeque};
 
-use crate::utils::{coords::Coordinates, direction::Direction};
+use crate::solution::{Answer, Solution};
+use crate::utils::{coords::Coordinates, direction::Direction, grid::Grid};
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
-fn get_start_position(input: &str) -> Coordinates<i16> {
-    let (x, y) = input
-        .lines()
-        .enumerate()
-        .find_map(|(y, line)| match line.find('S') {
-            Some(x) => Some((x, y)),
-            None => None,
-        })
-        .expect("S not found");
+fn get_start_position(grid: &Grid<char>) -> Coordinates<i16> {
+    let start = grid.position(|&c| c == 'S').expect("S not found");
 
-    Coordinates::new(x.try_into().unwrap(), y.try_into().unwrap())
+    Coordinates::new(start.x().try_into().unwrap(), start.y().try_into().unwrap())
 }
 
-fn get_start_direction(input: &str, position: Coordinates<i16>) -> Direction {
-    let left_position = position.step(Direction::Left);
+fn get_start_direction(grid: &Grid<char>, position: Coordinates<i16>) -> Direction {
+    let connects = |direction: Direction, pipes: &str| {
+        grid.get(position.step(direction))
+            .is_some_and(|pipe| pipes.contains(*pipe))
+    };
 
-    if left_position.x() >= 0
-        && left_position.y() >= 0
-        && (get_char_at_coord(input, left_position) == '-'
-            || get_char_at_coord(input, left_position) == 'F'
-            || get_char_at_coord(input, left_position) == 'L')
-    {
+    if connects(Direction::Left, "-FL") {
         Direction::Left
-    } else if get_char_at_coord(input, position.step(Direction::Right)) == '-'
-        || get_char_at_coord(input, position.step(Direction::Right)) == 'J'
-        || get_char_at_coord(input, position.step(Direction::Right)) == '7'
-    {
+    } else if connects(Direction::Right, "-J7") {
         Direction::Right
-    } else if get_char_at_coord(input, position.step(Direction::Up)) == '|'
-        || get_char_at_coord(input, position.step(Direction::Up)) == 'F'
-        || get_char_at_coord(input, position.step(Direction::Up)) == '7'
-    {
+    } else if connects(Direction::Up, "|F7") {
         Direction::Up
     } else {
         Direction::Down
@@ -84,20 +67,11 @@ fn get_next_direction(pipe: char, direction: Direction) -> Direction {
     }
 }
 
-fn get_char_at_coord(input: &str, position: Coordinates<i16>) -> char {
-    input
-        .lines()
-        .nth(position.y() as usize)
-        .unwrap()
-        .chars()
-        .nth(position.x() as usize)
-        .unwrap()
-}
-
 fn func1(input: &str) -> i32 {
-    let mut position = get_start_position(input);
+    let grid = Grid::parse(input, |c| c);
+    let mut position = get_start_position(&grid);
 
-    let mut dir = get_start_direction(input, position);
+    let mut dir = get_start_direction(&grid, position);
 
     let mut i = 0;
 
@@ -105,7 +79,7 @@ fn func1(input: &str) -> i32 {
         i += 1;
         position = position.step(dir);
 
-        let ch = get_char_at_coord(input, position);
+        let ch = *grid.get(position).expect("the loop never leaves the grid");
         if ch == 'S' {
             break;
         }
@@ -121,7 +95,7 @@ fn print_matrix(matrix: Vec<Vec<char>>) {
         for ch in row.iter() {
             print!("{ch}");
         }
-        println!("");
+        println!();
     }
 }
 
@@ -140,33 +114,33 @@ fn func2(input: &str) -> usize {
             match ch {
                 'S' => {
                     filled_positions.insert((x as i32, y as i32));
-                    expanded_map[(y * 3) + 0][(x * 3) + 0] = '#';
-                    expanded_map[(y * 3) + 0][(x * 3) + 1] = '#';
-                    expanded_map[(y * 3) + 0][(x * 3) + 2] = '#';
-                    expanded_map[(y * 3) + 1][(x * 3) + 0] = '#';
+                    expanded_map[y * 3][x * 3] = '#';
+                    expanded_map[y * 3][(x * 3) + 1] = '#';
+                    expanded_map[y * 3][(x * 3) + 2] = '#';
+                    expanded_map[(y * 3) + 1][x * 3] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 2] = '#';
-                    expanded_map[(y * 3) + 2][(x * 3) + 0] = '#';
+                    expanded_map[(y * 3) + 2][x * 3] = '#';
                     expanded_map[(y * 3) + 2][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 2][(x * 3) + 2] = '#';
                 }
                 '-' => {
-                    expanded_map[(y * 3) + 1][(x * 3) + 0] = '#';
+                    expanded_map[(y * 3) + 1][x * 3] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 2] = '#';
                 }
                 '|' => {
-                    expanded_map[(y * 3) + 0][(x * 3) + 1] = '#';
+                    expanded_map[y * 3][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 2][(x * 3) + 1] = '#';
                 }
                 'J' => {
-                    expanded_map[(y * 3) + 0][(x * 3) + 1] = '#';
+                    expanded_map[y * 3][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 1] = '#';
-                    expanded_map[(y * 3) + 1][(x * 3) + 0] = '#';
+                    expanded_map[(y * 3) + 1][x * 3] = '#';
                 }
                 'L' => {
-                    expanded_map[(y * 3) + 0][(x * 3) + 1] = '#';
+                    expanded_map[y * 3][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 2] = '#';
                 }
@@ -178,7 +152,7 @@ fn func2(input: &str) -> usize {
                 '7' => {
                     expanded_map[(y * 3) + 2][(x * 3) + 1] = '#';
                     expanded_map[(y * 3) + 1][(x * 3) + 1] = '#';
-                    expanded_map[(y * 3) + 1][(x * 3) + 0] = '#';
+                    expanded_map[(y * 3) + 1][x * 3] = '#';
                 }
                 _ => {
                     expanded_map[y * 3][x * 3] = '.';
@@ -208,84 +182,3 @@ fn func2(input: &str) -> usize {
 
     total_positions - filled_positions.len()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[rstest]
-    #[case(
-        4,
-        "-L|F7
-7S-7|
-L|7||
--L-J|
-L|-JF"
-    )]
-    #[case(
-        8,
-        "7-F7-
-.FJ|7
-SJLL7
-|F--J
-LJ.LJ"
-    )]
-    fn test_func1(#[case] expected: i32, #[case] input: &str) {
-        assert_eq!(expected, func1(input))
-    }
-
-    #[rstest]
-    #[case(
-        4,
-        "...........
-.S-------7.
-.|F-----7|.
-.||OOOOO||.
-.||OOOOO||.
-.|L-7OF-J|.
-.|II|O|II|.
-.L--JOL--J.
-.....O....."
-    )]
-    #[case(
-        4,
-        "..........
-.S------7.
-.|F----7|.
-.||OOOO||.
-.||OOOO||.
-.|L-7F-J|.
-.|II||II|.
-.L--JL--J.
-.........."
-    )]
-    #[case(
-        8,
-        ".F----7F7F7F7F-7....
-.|F--7||||||||FJ....
-.||.FJ||||||||L7....
-FJL7L7LJLJ||LJ.L-7..
-L--J.L7...LJS7F-7L7.
-....F-J..F7FJ|L7L7L7
-....L7.F7||L7|.L7L7|
-.....|FJLJ|FJ|F7|.LJ
-....FJL-7.||.||||...
-....L---J.LJ.LJLJ..."
-    )]
-    #[case(
-        10,
-        "FF7FSF7F7F7F7F7F---7
-L|LJ||||||||||||F--J
-FL-7LJLJ||||||LJL-77
-F--JF--7||LJLJIF7FJ-
-L---JF-JLJIIIIFJLJJ7
-|F|F-JF---7IIIL7L|7|
-|FFJF7L7F-JF7IIL---7
-7-L-JL7||F7|L7F-7F7|
-L.L7LFJ|||||FJL7||LJ
-L7JLJL-JLJLJL--JLJ.L"
-    )]
-    fn test_func2(#[case] expected: usize, #[case] input: &str) {
-        assert_eq!(expected, func2(input))
-    }
-}
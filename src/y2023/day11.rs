@@ -1,6 +1,4 @@
-use rstest::rstest;
-use std::time::Instant;
-
+use crate::solution::{Answer, Solution};
 use crate::utils::coords::Coordinates;
 
 // First star: 9918828
@@ -8,14 +6,16 @@ use crate::utils::coords::Coordinates;
 // Second star: 692506533832
 //          time:65.4287ms ->57.8711ms
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func2(input, 2));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func2(input, 2).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input, 1000000));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input, 1000000).into()
+    }
 }
 
 fn func2(input: &str, expansion: usize) -> usize {
@@ -32,15 +32,10 @@ fn func2(input: &str, expansion: usize) -> usize {
         .collect();
 
     let empty_cols: Vec<usize> = (0..input.lines().next().unwrap().len())
-        .filter_map(|x| {
-            if input
+        .filter(|&x| {
+            input
                 .lines()
                 .all(|line| line.chars().nth(x).unwrap() != '#')
-            {
-                Some(x)
-            } else {
-                None
-            }
         })
         .collect();
 
@@ -97,40 +92,7 @@ mod tests {
 
     use super::*;
 
-    #[rstest]
-    #[case(
-        12,
-        "..........
-..........
-..........
-..........
-..........
-.#........
-..........
-..........
-..........
-....#....."
-    )]
-    fn test_func1_cases(#[case] expected: usize, #[case] input: &str) {
-        assert_eq!(expected, func2(input, 2));
-    }
-
-    #[test]
-    fn test_func1() {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-
-        let result = 374;
-        assert_eq!(result, func2(input, 2));
-    }
+    use rstest::rstest;
 
     #[rstest]
     #[case(
@@ -165,6 +127,7 @@ mod tests {
         assert_eq!(expected, func2(input, expansion));
     }
 
+    #[ignore = "requires the puzzle input"]
     #[test]
     fn test_real() {
         let input = utils::io::read(2023, 11);
@@ -5,8 +5,9 @@ use nom::{
     sequence::separated_pair,
     IResult,
 };
-use rstest::rstest;
-use std::{cmp::min, collections::HashMap, time::Instant};
+use std::{cmp::min, collections::HashMap};
+
+use crate::solution::{Answer, Solution};
 
 fn springs(input: &str) -> IResult<&str, String> {
     many1(one_of("?#."))(input).map(|(res, parsed)| (res, parsed.iter().collect::<String>()))
@@ -25,14 +26,16 @@ fn parse_input(input: &str) -> IResult<&str, (String, Vec<usize>)> {
     separated_pair(springs, tag(" "), nums)(input)
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", input.lines().map(func1).sum::<usize>());
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input.lines().map(func1).sum::<usize>().into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", input.lines().map(func2).sum::<usize>());
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        input.lines().map(func2).sum::<usize>().into()
+    }
 }
 
 fn resolve_puzzle(
@@ -40,7 +43,7 @@ fn resolve_puzzle(
     numbers: Vec<usize>,
     memo: &mut HashMap<(String, Vec<usize>), usize>,
 ) -> usize {
-    if mapping == "" {
+    if mapping.is_empty() {
         if numbers.is_empty() {
             return 1;
         } else {
@@ -68,19 +71,18 @@ fn resolve_puzzle(
 
     let first_number = numbers[0];
 
-    if mapping.starts_with('#') || mapping.starts_with('?') {
-        if first_number <= mapping.len()
-            && !mapping[..first_number].contains('.')
-            && (first_number == mapping.len() || mapping.chars().nth(first_number).unwrap() != '#')
-        {
-            let next_index = min(first_number + 1, mapping.len());
-            result += resolve_puzzle(&mapping[next_index..], numbers[1..].to_vec(), memo)
-        }
+    if (mapping.starts_with('#') || mapping.starts_with('?'))
+        && first_number <= mapping.len()
+        && !mapping[..first_number].contains('.')
+        && (first_number == mapping.len() || mapping.chars().nth(first_number).unwrap() != '#')
+    {
+        let next_index = min(first_number + 1, mapping.len());
+        result += resolve_puzzle(&mapping[next_index..], numbers[1..].to_vec(), memo)
     }
 
     memo.insert((mapping.to_string(), numbers), result);
 
-    return result;
+    result
 }
 
 fn func1(input: &str) -> usize {
@@ -104,6 +106,7 @@ fn func2(input: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(1, "???.### 1,1,3")]
@@ -155,22 +158,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_func2() {
-        let input = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-
-        let expected = 525152;
-
-        let result = input.lines().map(func2).sum::<usize>();
-
-        assert_eq!(expected, result);
-    }
-
     #[rstest]
     #[case(1, "???.### 1,1,3")]
     #[case(16384, ".??..??...?##. 1,1,3")]
@@ -1,20 +1,18 @@
-use rstest::rstest;
-use std::{cmp::min, time::Instant};
+use std::cmp::min;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!(
-        "First star: {}",
-        input.split("\n\n").map(func1).sum::<usize>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
 
-    let start_time = Instant::now();
-    println!(
-        "Second star: {}",
-        input.split("\n\n").map(func2).sum::<usize>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input.split("\n\n").map(func1).sum::<usize>().into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        input.split("\n\n").map(func2).sum::<usize>().into()
+    }
 }
 
 fn func1(input: &str) -> usize {
@@ -28,7 +26,7 @@ fn detect_horizontal_fold(input: &str, multiplier: usize) -> Option<usize> {
 
     for (i, line) in input.lines().enumerate() {
         if &line == mirror.last().unwrap_or(&"")
-            && is_mirror(&input.lines().collect::<Vec<&str>>()[i..].to_vec(), &mirror)
+            && is_mirror(&input.lines().collect::<Vec<&str>>()[i..], &mirror)
         {
             return Some(i * multiplier);
         }
@@ -39,19 +37,7 @@ fn detect_horizontal_fold(input: &str, multiplier: usize) -> Option<usize> {
 }
 
 fn transpose_string(input: &str) -> String {
-    let lines: Vec<&str> = input.lines().collect();
-    let max_len = lines.iter().map(|line| line.len()).max().unwrap_or(0);
-
-    let transposed: Vec<String> = (0..max_len)
-        .map(|col| {
-            lines
-                .iter()
-                .filter_map(|line| line.chars().nth(col))
-                .collect::<String>()
-        })
-        .collect();
-
-    transposed.join("\n")
+    Grid::parse(input, |c| c).transpose().to_string()
 }
 
 fn detect_vertical_fold(input: &str, multiplier: usize) -> Option<usize> {
@@ -60,8 +46,8 @@ fn detect_vertical_fold(input: &str, multiplier: usize) -> Option<usize> {
     detect_horizontal_fold(transposed, multiplier)
 }
 
-fn is_mirror(input: &Vec<&str>, mirror: &Vec<&str>) -> bool {
-    let mut reversed = mirror.clone();
+fn is_mirror(input: &[&str], mirror: &[&str]) -> bool {
+    let mut reversed = mirror.to_vec();
     reversed.reverse();
 
     let min_len = min(reversed.len(), input.len());
@@ -79,9 +65,9 @@ fn detect_horizontal_fold_smudge(input: &str, multiplier: usize) -> Option<usize
     let mut mirror: Vec<&str> = vec![];
 
     for (i, line) in input.lines().enumerate() {
-        let differences = line_differences(&line, mirror.last().unwrap_or(&""));
+        let differences = line_differences(line, mirror.last().unwrap_or(&""));
         if differences <= 1
-            && is_smudged_mirror(&input.lines().collect::<Vec<&str>>()[i..].to_vec(), &mirror)
+            && is_smudged_mirror(&input.lines().collect::<Vec<&str>>()[i..], &mirror)
         {
             return Some(i * multiplier);
         }
@@ -97,8 +83,8 @@ fn detect_vertical_fold_smudge(input: &str, multiplier: usize) -> Option<usize>
     detect_horizontal_fold_smudge(transposed, multiplier)
 }
 
-fn is_smudged_mirror(input: &Vec<&str>, mirror: &Vec<&str>) -> bool {
-    let mut reversed = mirror.clone();
+fn is_smudged_mirror(input: &[&str], mirror: &[&str]) -> bool {
+    let mut reversed = mirror.to_vec();
     reversed.reverse();
 
     let min_len = min(reversed.len(), input.len());
@@ -112,7 +98,7 @@ fn is_smudged_mirror(input: &Vec<&str>, mirror: &Vec<&str>) -> bool {
 }
 
 fn line_differences(line1: &str, line2: &str) -> usize {
-    if line2 == "" {
+    if line2.is_empty() {
         return 50;
     }
     line1
@@ -125,6 +111,7 @@ fn line_differences(line1: &str, line2: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(
@@ -194,29 +181,6 @@ mod tests {
         assert_eq!(expected, func1(input))
     }
 
-    #[test]
-    fn test_func1_complete() {
-        let input = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-
-        let expected = 405;
-
-        assert_eq!(expected, input.split("\n\n").map(func1).sum::<usize>());
-    }
-
     #[rstest]
     #[case(
         300,
@@ -283,27 +247,4 @@ mod tests {
     fn test_func2(#[case] expected: usize, #[case] input: &str) {
         assert_eq!(expected, func2(input))
     }
-
-    #[test]
-    fn test_func2_complete() {
-        let input = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-
-        let expected = 400;
-
-        assert_eq!(expected, input.split("\n\n").map(func2).sum::<usize>());
-    }
 }
@@ -1,172 +1,69 @@
-use rstest::rstest;
-use std::{
-    collections::{HashMap, HashSet},
-    time::Instant,
-};
+use crate::solution::{Answer, Solution};
+use crate::utils::{cycles, grid::Grid};
 
-use crate::utils::transposer::{traspose_string, traspose_string_vec};
+pub struct Solver;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", slide_once(input));
-    println!("\t time:{:?}", start_time.elapsed());
-
-    let start_time = Instant::now();
-    println!("Second star: {}", slide_infinitely(input));
-    println!("\t time:{:?}", start_time.elapsed());
-}
-
-fn slide_once(input: &str) -> usize {
-    let processed_input = move_north(input.to_string());
-
-    processed_input
-        .lines()
-        .enumerate()
-        .map(|(i, line)| line.matches('O').count() * (processed_input.lines().count() - i))
-        .sum()
-}
-
-fn slide_infinitely(input: &str) -> usize {
-    let mut processed_input = input.to_string();
-
-    let mut visited_states = HashMap::new();
-
-    let mut i = 0;
-    loop {
-        processed_input = move_north(processed_input);
-        processed_input = move_west(processed_input);
-        processed_input = move_south(processed_input);
-        processed_input = move_east(processed_input);
-        i += 1;
-
-        if visited_states.contains_key(&processed_input) {
-            break;
-        }
-        visited_states.insert(processed_input.clone(), i);
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        slide_once(input).into()
     }
 
-    let iterations_left = (1000000000 - i) % (i - visited_states.get(&processed_input).unwrap());
-
-    i = 0;
-
-    loop {
-        processed_input = move_north(processed_input);
-        processed_input = move_west(processed_input);
-        processed_input = move_south(processed_input);
-        processed_input = move_east(processed_input);
-
-        i += 1;
-        if i == iterations_left {
-            break;
-        }
+    fn part2(&self, input: &str) -> Answer {
+        slide_infinitely(input).into()
     }
-    dbg!(i);
+}
 
-    processed_input
-        .lines()
+fn north_load(grid: &Grid<char>) -> usize {
+    grid.rows()
         .enumerate()
-        .map(|(i, line)| line.matches('O').count() * (processed_input.lines().count() - i))
+        .map(|(i, row)| row.iter().filter(|&&ch| ch == 'O').count() * (grid.height() - i))
         .sum()
 }
 
-fn move_east(processed_input: String) -> String {
-    move_down(processed_input.lines().collect()).join("\n")
-}
-
-fn move_south(processed_input: String) -> String {
-    let trasposed_input = traspose_string(&processed_input);
-
-    traspose_string_vec(move_down(trasposed_input.lines().collect()))
+fn slide_once(input: &str) -> usize {
+    north_load(&move_north(&Grid::parse(input, |c| c)))
 }
 
-fn move_west(processed_input: String) -> String {
-    move_up(processed_input.lines().collect()).join("\n")
+fn spin_cycle(grid: &Grid<char>) -> Grid<char> {
+    move_east(&move_south(&move_west(&move_north(grid))))
 }
 
-fn move_north(input: String) -> String {
-    let trasposed_input = traspose_string(&input);
-
-    traspose_string_vec(move_up(trasposed_input.lines().collect()))
+fn slide_infinitely(input: &str) -> usize {
+    let grid = cycles::state_at(Grid::parse(input, |c| c), spin_cycle, 1_000_000_000);
+    north_load(&grid)
 }
 
-fn move_down(input: Vec<&str>) -> Vec<String> {
-    input.iter().fold(Vec::new(), |mut acc, line| {
-        let new_line = move_up_stones(line.chars().rev().collect::<String>());
-        acc.push(new_line.chars().rev().collect::<String>());
-        acc
-    })
+/// Rolls every stone as far to the start of its row as it goes.
+fn move_west(grid: &Grid<char>) -> Grid<char> {
+    let mut grid = grid.clone();
+    grid.rows_mut().for_each(move_up_stones);
+    grid
 }
 
-fn move_up(input: Vec<&str>) -> Vec<String> {
-    input.iter().fold(Vec::new(), |mut acc, line| {
-        let new_line = move_up_stones(line.to_string());
-        acc.push(new_line);
-        acc
-    })
+fn move_east(grid: &Grid<char>) -> Grid<char> {
+    move_west(&grid.flip_horizontal()).flip_horizontal()
 }
 
-fn move_up_stones(line: String) -> String {
-    let mut new_line_vec: Vec<char> = vec![];
-
-    for (i, ch) in line.chars().enumerate() {
-        if ch == 'O' {
-            new_line_vec.push('O');
-        }
-        if ch == '#' {
-            let empty_spaces = i - new_line_vec.len();
-            new_line_vec.append(&mut vec!['.'; empty_spaces].clone());
-            new_line_vec.push('#')
-        }
-    }
-
-    let empty_spaces = line.len() - new_line_vec.len();
-
-    new_line_vec.append(&mut vec!['.'; empty_spaces].clone());
-
-    new_line_vec.iter().collect()
+fn move_north(grid: &Grid<char>) -> Grid<char> {
+    move_west(&grid.transpose()).transpose()
 }
 
-fn func2(input: &str) -> bool {
-    todo!()
+fn move_south(grid: &Grid<char>) -> Grid<char> {
+    move_west(&grid.rotate_clockwise()).rotate_counterclockwise()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // #[rstest]
-    // #[case()]
-    // fn test_func1(#[case] expected: u32, #[case] input: &str) {}
-
-    #[test]
-    fn test_func1() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let expected = 136;
-        assert_eq!(expected, slide_once(input));
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        let expected = 64;
-        assert_eq!(expected, slide_infinitely(input));
+/// Moves the round stones of `line` to its start, each stopping against the cube rock or the
+/// stone before it.
+fn move_up_stones(line: &mut [char]) {
+    let mut free = 0;
+    for i in 0..line.len() {
+        match line[i] {
+            'O' => {
+                line.swap(free, i);
+                free += 1;
+            }
+            '#' => free = i + 1,
+            _ => {}
+        }
     }
 }
@@ -1,6 +1,6 @@
-use nom::combinator::value;
-use rstest::rstest;
-use std::{collections::BTreeMap, time::Instant};
+use std::collections::BTreeMap;
+
+use crate::solution::{Answer, Solution};
 
 enum Operation {
     Add(String, u32),
@@ -36,7 +36,7 @@ impl LensBox {
 
     fn remove_lens(&mut self, label: &str) {
         if self.lens_map.contains_key(label) {
-            let index = self.lens_map.get(label).unwrap().clone();
+            let index = *self.lens_map.get(label).unwrap();
             self.lens_array.remove(index);
             self.lens_map.remove(label);
             let cloned_map = self.lens_map.clone();
@@ -69,17 +69,16 @@ impl LensBox {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!(
-        "First star: {}",
-        input.split(",").map(holyday_hash).sum::<u32>()
-    );
-    println!("\t time:{:?}", start_time.elapsed());
-
-    let start_time = Instant::now();
-    println!("Second star: {}", configure_lenses(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        input.split(",").map(holyday_hash).sum::<u32>().into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        configure_lenses(input).into()
+    }
 }
 
 fn holyday_hash(input: &str) -> u32 {
@@ -96,21 +95,23 @@ fn holyday_hash(input: &str) -> u32 {
 fn configure_lenses(input: &str) -> usize {
     //1. calcular el hash de la etiqueta
 
-    let boxes = input
-        .split(",")
-        .fold(vec![LensBox::new(); 256], |mut boxes, instruction| {
-            match process_instruction(instruction) {
-                Operation::Add(lable, focal) => {
-                    let hash = holyday_hash(lable.as_str());
-                    boxes[hash as usize].introduce_lens(&lable, focal);
-                }
-                Operation::Remove(lable) => {
-                    let hash = holyday_hash(lable.as_str());
-                    boxes[hash as usize].remove_lens(&lable);
+    let boxes =
+        input
+            .trim_end()
+            .split(",")
+            .fold(vec![LensBox::new(); 256], |mut boxes, instruction| {
+                match process_instruction(instruction) {
+                    Operation::Add(lable, focal) => {
+                        let hash = holyday_hash(lable.as_str());
+                        boxes[hash as usize].introduce_lens(&lable, focal);
+                    }
+                    Operation::Remove(lable) => {
+                        let hash = holyday_hash(lable.as_str());
+                        boxes[hash as usize].remove_lens(&lable);
+                    }
                 }
-            }
-            boxes
-        });
+                boxes
+            });
 
     let mut total = 0;
     for (i, lens_box) in boxes.iter().enumerate() {
@@ -137,6 +138,7 @@ fn process_instruction(input: &str) -> Operation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[rstest]
     #[case(52, "HASH")]
@@ -156,14 +158,7 @@ mod tests {
     }
 
     #[test]
-    fn test_hollyday_hash_complete() {
-        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        let expected = 1320;
-        assert_eq!(expected, input.split(",").map(holyday_hash).sum::<u32>())
-    }
-
-    #[test]
-    fn test_LensBox() {
+    fn test_lens_box() {
         let mut lens_box = LensBox::new();
 
         lens_box.introduce_lens("a", 3);
@@ -182,11 +177,4 @@ mod tests {
         lens_box.remove_lens("a");
         assert_eq!(None, lens_box.get_lens("a"));
     }
-
-    #[test]
-    fn test_configure_lenses() {
-        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        let expected = 145;
-        assert_eq!(expected, configure_lenses(input));
-    }
 }
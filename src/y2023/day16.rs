@@ -1,72 +1,58 @@
-use crate::utils::{coords::Coordinates, direction::Direction};
-use rstest::rstest;
-use std::{
-    collections::{HashSet, VecDeque},
-    time::Instant,
+use std::collections::VecDeque;
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{
+    coords::Coordinates,
+    direction::{Direction, DirectionSet},
+    grid::Grid,
 };
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn func1(input: &str) -> usize {
-    calculate_energy(input, Coordinates::new(0, 0), Direction::Right)
+    let grid = Grid::parse(input, |c| c);
+    calculate_energy(&grid, Coordinates::new(0, 0), Direction::Right)
 }
 
 fn calculate_energy(
-    input: &str,
+    grid: &Grid<char>,
     starting_coord: Coordinates<i32>,
     starting_direction: Direction,
 ) -> usize {
-    let mut energized = HashSet::new();
+    let mut visited = Grid::filled(grid.width(), grid.height(), DirectionSet::new());
     let mut q: VecDeque<(Coordinates<i32>, Direction)> = VecDeque::new();
     q.push_back((starting_coord, starting_direction));
 
-    let mut visited_mirrors = HashSet::new();
-
     while !q.is_empty() {
         if let Some((coord, direction)) = q.pop_front() {
-            if !is_in_bounds(coord, input) {
+            let (Some(&ch), Some(directions)) = (grid.get(coord), visited.get_mut(coord)) else {
                 continue;
-            }
-            let ch = input
-                .lines()
-                .nth(coord.y() as usize)
-                .unwrap()
-                .chars()
-                .nth(coord.x() as usize)
-                .unwrap();
-
-            if ch != '.' {
-                if visited_mirrors.contains(&(coord, direction)) {
-                    continue;
-                }
+            };
 
-                visited_mirrors.insert((coord, direction));
+            if !directions.insert(direction) {
+                continue;
             }
-            energized.insert(coord);
             let (new_direction, optional_direction) = get_next_direction(ch, direction);
             q.push_back((coord.step(new_direction), new_direction));
-            match optional_direction {
-                Some(optional) => q.push_back((coord.step(optional), optional)),
-                None => (),
+            if let Some(optional) = optional_direction {
+                q.push_back((coord.step(optional), optional))
             };
         }
     }
-    energized.len()
-}
-
-fn is_in_bounds(coord: Coordinates<i32>, input: &str) -> bool {
-    !(coord.x() < 0
-        || coord.y() < 0
-        || coord.x() >= input.lines().next().unwrap().len().try_into().unwrap()
-        || coord.y() >= input.lines().next().unwrap().len().try_into().unwrap())
+    visited
+        .values()
+        .filter(|directions| !directions.is_empty())
+        .count()
 }
 
 fn get_next_direction(ch: char, direction: Direction) -> (Direction, Option<Direction>) {
@@ -103,78 +89,28 @@ fn get_next_direction(ch: char, direction: Direction) -> (Direction, Option<Dire
 }
 
 fn func2(input: &str) -> usize {
-    let col_1 = (0..input.lines().count())
-        .map(|y| {
-            calculate_energy(
-                input,
-                Coordinates::new(0, y.try_into().unwrap()),
-                Direction::Right,
-            )
-        })
+    let grid = Grid::parse(input, |c| c);
+    let width: i32 = grid.width().try_into().unwrap();
+    let height: i32 = grid.height().try_into().unwrap();
+
+    let col_1 = (0..height)
+        .map(|y| calculate_energy(&grid, Coordinates::new(0, y), Direction::Right))
         .max()
         .unwrap();
-    let col_2 = (0..input.lines().count())
-        .map(|y| {
-            calculate_energy(
-                input,
-                Coordinates::new(
-                    (input.lines().next().unwrap().len() - 1)
-                        .try_into()
-                        .unwrap(),
-                    y.try_into().unwrap(),
-                ),
-                Direction::Left,
-            )
-        })
+    let col_2 = (0..height)
+        .map(|y| calculate_energy(&grid, Coordinates::new(width - 1, y), Direction::Left))
         .max()
         .unwrap();
 
-    let row_1 = (0..input.lines().next().unwrap().len())
-        .map(|x| {
-            calculate_energy(
-                input,
-                Coordinates::new(x.try_into().unwrap(), 0),
-                Direction::Right,
-            )
-        })
+    let row_1 = (0..width)
+        .map(|x| calculate_energy(&grid, Coordinates::new(x, 0), Direction::Right))
         .max()
         .unwrap();
 
-    let row_2 = (0..input.lines().next().unwrap().len())
-        .map(|x| {
-            calculate_energy(
-                input,
-                Coordinates::new(
-                    x.try_into().unwrap(),
-                    (input.lines().count() - 1).try_into().unwrap(),
-                ),
-                Direction::Left,
-            )
-        })
+    let row_2 = (0..width)
+        .map(|x| calculate_energy(&grid, Coordinates::new(x, height - 1), Direction::Left))
         .max()
         .unwrap();
 
-    *vec![row_2, row_1, col_2, col_1].iter().max().unwrap()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_func2() {
-        let input = r#".|...\....
-|.-.\.....
-.....|-...
-........|.
-..........
-.........\
-..../.\\..
-.-.-/..|..
-.|....-|.\
-..//.|...."#;
-        let expected = 46;
-
-        assert_eq!(expected, func1(input));
-    }
+    *[row_2, row_1, col_2, col_1].iter().max().unwrap()
 }
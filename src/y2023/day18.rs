@@ -1,75 +1,45 @@
-use rstest::rstest;
-use std::{i32, mem::Discriminant, time::Instant};
+use crate::solution::{Answer, Solution};
+use crate::utils::{coords::Coordinates, direction::Direction, geometry};
 
-use crate::utils::{coords::Coordinates, direction::Direction};
+pub struct Solver;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", calculate_area(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        calculate_area(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", calculate_area_hex(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        calculate_area_hex(input).into()
+    }
 }
 
 fn calculate_area(input: &str) -> i128 {
-    let mut coord = Coordinates::new(0, 0);
-    let mut res = 0;
-    let mut dim = 1;
-    for line in input.lines() {
-        let (direction, steps) = get_direction_steps(line);
-
-        let new_coord = coord.step_by(direction, steps);
-
-        if direction == Direction::Down || direction == Direction::Left {
-            dim += steps
-        }
-
-        res += calculate_pair(coord, new_coord);
-        coord = new_coord;
-    }
-
-    res.abs() / 2 + dim
+    dig(input.lines().map(get_direction_steps))
 }
 
-fn calculate_pair(coord1: Coordinates<i128>, coord2: Coordinates<i128>) -> i128 {
-    coord1.x() * coord2.y() - (coord1.y() * coord2.x())
+/// Number of cells of the lagoon, the trench included.
+fn dig(plan: impl Iterator<Item = (Direction, i128)>) -> i128 {
+    let vertices: Vec<Coordinates<i128>> = plan
+        .scan(Coordinates::origin(), |coord, (direction, steps)| {
+            *coord = coord.step_by(direction, steps);
+            Some(*coord)
+        })
+        .collect();
+
+    geometry::lattice_points(&vertices)
 }
 
 fn get_direction_steps(input: &str) -> (Direction, i128) {
     let mut split_it = input.split(" ");
 
-    let direction = match split_it.next().unwrap() {
-        "R" => Direction::Right,
-        "D" => Direction::Down,
-        "L" => Direction::Left,
-        "U" => Direction::Up,
-        _ => panic!(),
-    };
+    let direction = Direction::parse(split_it.next().unwrap()).expect("unknown direction");
     let steps = split_it.next().unwrap().parse::<i128>().unwrap();
 
     (direction, steps)
 }
 
 fn calculate_area_hex(input: &str) -> i128 {
-    let mut coord = Coordinates::new(0, 0);
-    let mut res = 0;
-    let mut dim = 1;
-    for line in input.lines() {
-        let (direction, steps) = get_direction_steps_from_hex(line);
-
-        let new_coord = coord.step_by(direction, steps);
-
-        if direction == Direction::Down || direction == Direction::Left {
-            dim += steps
-        }
-
-        res += calculate_pair(coord, new_coord);
-        coord = new_coord;
-    }
-
-    res.abs() / 2 + dim
+    dig(input.lines().map(get_direction_steps_from_hex))
 }
 
 fn get_direction_steps_from_hex(line: &str) -> (Direction, i128) {
@@ -87,61 +57,3 @@ fn get_direction_steps_from_hex(line: &str) -> (Direction, i128) {
 
     (direction, steps)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_calculate_area_small() {
-        let input = "R 1 (#70c710)
-D 1 (#70c710)
-L 1 (#70c710)
-U 1 (#7a21e3)";
-        let res = 4;
-
-        assert_eq!(res, calculate_area(input));
-    }
-
-    #[test]
-    fn test_calculate_area() {
-        let input = "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)";
-        let res = 62;
-
-        assert_eq!(res, calculate_area(input));
-    }
-
-    #[test]
-    fn test_calculate_area_hex() {
-        let input = "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)";
-        let res = 952408144115;
-
-        assert_eq!(res, calculate_area_hex(input));
-    }
-}
@@ -1,27 +1,28 @@
-use itertools::Intersperse;
 use nom::{
-    bytes::complete::{tag, take_until},
+    bytes::complete::tag,
     character::complete::{alpha0, anychar, digit0, digit1, newline},
     multi::{fold_many1, many1, separated_list0},
     sequence::{delimited, preceded, separated_pair, terminated, tuple},
-    IResult, Parser,
-};
-use rstest::rstest;
-use std::{
-    collections::{BTreeMap, VecDeque},
-    time::Instant,
+    IResult,
 };
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::solution::{Answer, Solution};
+use crate::utils::intervals::IntervalSet;
+
+type Interval = IntervalSet<u128>;
+type WorkflowIntervals = (String, Interval, Interval, Interval, Interval);
 
-use crate::utils::parser::get_number_from_line;
+pub struct Solver;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", calculate_accepted_pieces(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        calculate_accepted_pieces(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", calculate_all_accepted_pieces(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        calculate_all_accepted_pieces(input).into()
+    }
 }
 
 fn parse_input(input: &str) -> IResult<&str, (Vec<Piece>, BTreeMap<String, Workflow>)> {
@@ -138,10 +139,11 @@ impl Workflow {
         let mut iter_rules = self.rules.iter();
         loop {
             match iter_rules.next() {
-                Some(rule) => match rule.get_destination(piece) {
-                    Some(destination) => return destination,
-                    None => (),
-                },
+                Some(rule) => {
+                    if let Some(destination) = rule.get_destination(piece) {
+                        return destination;
+                    }
+                }
                 None => return self.default.clone(),
             }
         }
@@ -149,36 +151,26 @@ impl Workflow {
 
     fn calculate_new_intervals(
         &self,
-        x: (u128, u128),
-        m: (u128, u128),
-        a: (u128, u128),
-        s: (u128, u128),
-    ) -> (
-        VecDeque<(
-            String,
-            (u128, u128),
-            (u128, u128),
-            (u128, u128),
-            (u128, u128),
-        )>,
-        u128,
-    ) {
+        x: Interval,
+        m: Interval,
+        a: Interval,
+        s: Interval,
+    ) -> (VecDeque<WorkflowIntervals>, u128) {
         let mut new_intervals = VecDeque::new();
         let mut accepted_total = 0;
         let mut new_x = x;
         let mut new_m = m;
         let mut new_a = a;
         let mut new_s = s;
-        let mut accepted = 0;
-        let mut new_interval = None;
+        let mut accepted;
+        let mut new_interval;
 
         for rule in &self.rules {
             (new_interval, new_x, new_m, new_a, new_s, accepted) =
                 rule.calculate_new_interval(new_x, new_m, new_a, new_s);
             accepted_total += accepted;
-            match new_interval {
-                Some(interval) => new_intervals.push_back(interval),
-                None => (),
+            if let Some(interval) = new_interval {
+                new_intervals.push_back(interval)
             }
         }
 
@@ -186,13 +178,7 @@ impl Workflow {
             Destination::Workflow(other) => {
                 new_intervals.push_back((other.to_string(), new_x, new_m, new_a, new_s))
             }
-            Destination::Accepted => {
-                let total_x = new_x.1 - new_x.0 + 1;
-                let total_m = new_m.1 - new_m.0 + 1;
-                let total_a = new_a.1 - new_a.0 + 1;
-                let total_s = new_s.1 - new_s.0 + 1;
-                accepted_total += total_s * total_m * total_x * total_a
-            }
+            Destination::Accepted => accepted_total += combinations(&new_x, &new_m, &new_a, &new_s),
             Destination::Regected => (),
         }
 
@@ -200,6 +186,11 @@ impl Workflow {
     }
 }
 
+/// Number of pieces with every rating in its interval.
+fn combinations(x: &Interval, m: &Interval, a: &Interval, s: &Interval) -> u128 {
+    x.total_len() * m.total_len() * a.total_len() * s.total_len()
+}
+
 #[derive(Debug, Clone)]
 struct Rules {
     rating: String,
@@ -213,30 +204,30 @@ impl Rules {
         match self.rating.as_str() {
             "x" => {
                 if self.follows_rule(piece.x) {
-                    return Some(self.destination.clone());
+                    Some(self.destination.clone())
                 } else {
-                    return None;
+                    None
                 }
             }
             "m" => {
                 if self.follows_rule(piece.m) {
-                    return Some(self.destination.clone());
+                    Some(self.destination.clone())
                 } else {
-                    return None;
+                    None
                 }
             }
             "a" => {
                 if self.follows_rule(piece.a) {
-                    return Some(self.destination.clone());
+                    Some(self.destination.clone())
                 } else {
-                    return None;
+                    None
                 }
             }
             "s" => {
                 if self.follows_rule(piece.s) {
-                    return Some(self.destination.clone());
+                    Some(self.destination.clone())
                 } else {
-                    return None;
+                    None
                 }
             }
 
@@ -252,27 +243,33 @@ impl Rules {
         }
     }
 
+    /// The part of `interval` that follows the rule, and the rest.
+    fn split_interval(&self, interval: &Interval) -> (Interval, Interval) {
+        match self.operator {
+            '<' => interval.split_at(self.quantity),
+            '>' => {
+                let (below, above) = interval.split_at(self.quantity + 1);
+                (above, below)
+            }
+            _ => panic!(),
+        }
+    }
+
     fn calculate_new_interval(
         &self,
-        x: (u128, u128),
-        m: (u128, u128),
-        a: (u128, u128),
-        s: (u128, u128),
+        x: Interval,
+        m: Interval,
+        a: Interval,
+        s: Interval,
     ) -> (
-        Option<(
-            String,
-            (u128, u128),
-            (u128, u128),
-            (u128, u128),
-            (u128, u128),
-        )>,
-        (u128, u128),
-        (u128, u128),
-        (u128, u128),
-        (u128, u128),
+        Option<WorkflowIntervals>,
+        Interval,
+        Interval,
+        Interval,
+        Interval,
         u128,
     ) {
-        let mut new_interval = ("".to_string(), x, m, a, s);
+        let mut new_interval = ("".to_string(), x.clone(), m.clone(), a.clone(), s.clone());
         let mut df_x = x;
         let mut df_m = m;
         let mut df_a = a;
@@ -280,50 +277,10 @@ impl Rules {
         let mut accepted_total = 0;
 
         match self.rating.as_str() {
-            "x" => match self.operator {
-                '<' => {
-                    new_interval.1 = (x.0, self.quantity - 1);
-                    df_x = (self.quantity, x.1);
-                }
-                '>' => {
-                    new_interval.1 = (self.quantity + 1, x.1);
-                    df_x = (x.0, self.quantity);
-                }
-                _ => panic!(),
-            },
-            "m" => match self.operator {
-                '<' => {
-                    new_interval.2 = (m.0, self.quantity - 1);
-                    df_m = (self.quantity, m.1);
-                }
-                '>' => {
-                    new_interval.2 = (self.quantity + 1, m.1);
-                    df_m = (m.0, self.quantity);
-                }
-                _ => panic!(),
-            },
-            "a" => match self.operator {
-                '<' => {
-                    new_interval.3 = (a.0, self.quantity - 1);
-                    df_a = (self.quantity, a.1);
-                }
-                '>' => {
-                    new_interval.3 = (self.quantity + 1, a.1);
-                    df_a = (a.0, self.quantity);
-                }
-                _ => panic!(),
-            },
-            "s" => match self.operator {
-                '<' => {
-                    new_interval.4 = (s.0, self.quantity - 1);
-                    df_s = (self.quantity, s.1);
-                }
-                '>' => {
-                    new_interval.4 = (self.quantity + 1, s.1);
-                    df_s = (s.0, self.quantity);
-                }
-                _ => panic!(),
-            },
+            "x" => (new_interval.1, df_x) = self.split_interval(&df_x),
+            "m" => (new_interval.2, df_m) = self.split_interval(&df_m),
+            "a" => (new_interval.3, df_a) = self.split_interval(&df_a),
+            "s" => (new_interval.4, df_s) = self.split_interval(&df_s),
 
             _ => panic!(),
         }
@@ -334,12 +291,8 @@ impl Rules {
                 Some(new_interval)
             }
             Destination::Accepted => {
-                let total_x = new_interval.1 .1 - new_interval.1 .0 + 1;
-                let total_m = new_interval.2 .1 - new_interval.2 .0 + 1;
-                let total_a = new_interval.3 .1 - new_interval.3 .0 + 1;
-                let total_s = new_interval.4 .1 - new_interval.4 .0 + 1;
-
-                accepted_total += total_s * total_m * total_x * total_a;
+                let (_, x, m, a, s) = &new_interval;
+                accepted_total += combinations(x, m, a, s);
                 None
             }
             Destination::Regected => None,
@@ -394,7 +347,14 @@ fn calculate_all_accepted_pieces(input: &str) -> usize {
     let (_, (_, workflows)) = parse_input(input).expect("input malformed");
 
     let mut q = VecDeque::new();
-    q.push_back(("in".to_string(), (1, 4000), (1, 4000), (1, 4000), (1, 4000)));
+    let ratings = Interval::from(1..=4000);
+    q.push_back((
+        "in".to_string(),
+        ratings.clone(),
+        ratings.clone(),
+        ratings.clone(),
+        ratings,
+    ));
 
     let mut all_accepted_pieces = 0;
 
@@ -410,85 +370,3 @@ fn calculate_all_accepted_pieces(input: &str) -> usize {
 
     all_accepted_pieces
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    // #[rstest]
-    // #[case()]
-    // fn test_func1(#[case] expected: u128, #[case] input: &str) {}
-
-    #[test]
-    fn test_func1() {
-        let input = "px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}
-";
-        let expected = 19114;
-        assert_eq!(expected, calculate_accepted_pieces(input));
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}
-";
-        let expected = 167409079868000;
-        assert_eq!(expected, calculate_all_accepted_pieces(input));
-    }
-
-    #[rstest]
-    #[case(
-        169600000000000,
-        "in{s<1351:R,A}
-
-
-{x=787,m=2655,a=1222,s=2876}
-"
-    )]
-    #[case(
-        78720000000000,
-        "in{s<1351:R,qqz}
-qqz{s>2770:A,m<1801:R,R}
-
-
-{x=787,m=2655,a=1222,s=2876}
-"
-    )]
-
-    fn test_small_test(#[case] expected: usize, #[case] input: &str) {
-        assert_eq!(expected, calculate_all_accepted_pieces(input));
-    }
-}
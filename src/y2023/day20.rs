@@ -5,13 +5,10 @@ use nom::{
     sequence::terminated,
     IResult,
 };
-use rstest::rstest;
-use std::{
-    collections::{HashMap, VecDeque},
-    os::unix::raw::uid_t,
-    time::Instant,
-    usize,
-};
+use std::collections::{HashMap, VecDeque};
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{cycles, graph::Graph};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum ModuleType {
@@ -100,46 +97,56 @@ fn parse_module(input: &str) -> IResult<&str, (String, Module)> {
     ))
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
-
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
-}
-
-fn func1(input: &str) -> usize {
-    let (_, mut modules) = parse_input(input).expect("Input malformed");
+pub struct Solver;
 
-    let mut ins: HashMap<String, Vec<String>> = HashMap::new();
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    for (name, module) in &modules {
-        for (_, out_names) in &module.out {
-            let list_of_in = ins.entry(out_names.clone()).or_insert(vec![]);
-            list_of_in.push(name.clone());
-        }
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
     }
+}
 
-    for (module_name, input_modules) in ins {
-        let mut actual_module = (*modules.get(&module_name).unwrap_or(&Module {
+/// Remembers the inputs of every module, adding the untyped ones that only receive pulses,
+/// and returns the graph from each module to its inputs.
+fn connect_inputs(modules: &mut HashMap<String, Module>) -> Graph<String> {
+    let ins = modules
+        .iter()
+        .flat_map(|(name, module)| {
+            module
+                .out
+                .iter()
+                .map(move |(_, out_name)| (name.clone(), out_name.clone()))
+        })
+        .collect::<Graph<_>>()
+        .reversed();
+
+    for module_name in ins.nodes() {
+        let mut actual_module = (*modules.get(module_name).unwrap_or(&Module {
             input: HashMap::new(),
             module_type: ModuleType::End,
             out: Vec::new(),
         }))
         .clone();
 
-        actual_module.input = input_modules
-            .iter()
-            .fold(HashMap::new(), |mut acc, input_module| {
-                acc.insert(input_module.to_string(), false);
-                acc
-            });
+        actual_module.input = ins
+            .successors(module_name)
+            .map(|input_module| (input_module.clone(), false))
+            .collect();
 
-        modules.insert(module_name, actual_module);
+        modules.insert(module_name.clone(), actual_module);
     }
 
+    ins
+}
+
+fn func1(input: &str) -> usize {
+    let (_, mut modules) = parse_input(input).expect("Input malformed");
+
+    connect_inputs(&mut modules);
+
     let mut i = 0;
 
     let mut low_total = 0;
@@ -168,7 +175,7 @@ fn press_button(mut modules: HashMap<String, Module>) -> (usize, usize, HashMap<
         let (in_signal, module_name, previous) = q.pop_front().unwrap();
         let mut actual_module = (*modules
             .get(&module_name)
-            .expect(format!("modules does not have {}", module_name).as_str()))
+            .unwrap_or_else(|| panic!("modules does not have {}", module_name)))
         .clone();
 
         if actual_module.module_type == ModuleType::End {
@@ -178,7 +185,7 @@ fn press_button(mut modules: HashMap<String, Module>) -> (usize, usize, HashMap<
         let _ = actual_module.process_signal(in_signal, previous);
         modules.insert(module_name.clone(), actual_module.clone());
 
-        if actual_module.module_type == ModuleType::FlipFlop && in_signal == true {
+        if actual_module.module_type == ModuleType::FlipFlop && in_signal {
             continue;
         }
 
@@ -198,81 +205,85 @@ fn press_button(mut modules: HashMap<String, Module>) -> (usize, usize, HashMap<
     (low_pulses, high_pulses, modules)
 }
 
-fn func2(input: &str) -> usize {
-    let (_, mut modules) = parse_input(input).expect("Input malformed");
+/// Presses the button once and reports which modules sent a high pulse to `watched`.
+fn press_button_2(
+    mut modules: HashMap<String, Module>,
+    watched: &str,
+) -> (Vec<String>, HashMap<String, Module>) {
+    let mut q = VecDeque::new();
+
+    q.push_back((false, "broadcaster".to_string(), "broadcaster".to_string()));
 
-    let mut ins: HashMap<String, Vec<String>> = HashMap::new();
+    let mut high_senders = Vec::new();
 
-    for (name, module) in &modules {
-        for (_, out_names) in &module.out {
-            let list_of_in = ins.entry(out_names.clone()).or_insert(vec![]);
-            list_of_in.push(name.clone());
+    while let Some((in_signal, module_name, previous)) = q.pop_front() {
+        if module_name == watched && in_signal {
+            high_senders.push(previous.clone());
         }
-    }
 
-    for (module_name, input_modules) in ins {
-        let mut actual_module = (*modules.get(&module_name).unwrap_or(&Module {
-            input: HashMap::new(),
-            module_type: ModuleType::End,
-            out: Vec::new(),
-        }))
+        let mut actual_module = (*modules
+            .get(&module_name)
+            .unwrap_or_else(|| panic!("modules does not have {}", module_name)))
         .clone();
 
-        actual_module.input = input_modules
-            .iter()
-            .fold(HashMap::new(), |mut acc, input_module| {
-                acc.insert(input_module.to_string(), false);
-                acc
-            });
+        if actual_module.module_type == ModuleType::End {
+            continue;
+        }
+
+        let _ = actual_module.process_signal(in_signal, previous);
+        modules.insert(module_name.clone(), actual_module.clone());
+
+        if actual_module.module_type == ModuleType::FlipFlop && in_signal {
+            continue;
+        }
 
-        modules.insert(module_name, actual_module);
+        for out_singal in &actual_module.out {
+            q.push_back((out_singal.0, out_singal.1.clone(), module_name.clone()));
+        }
     }
 
+    (high_senders, modules)
+}
+
+fn func2(input: &str) -> usize {
+    let (_, mut modules) = parse_input(input).expect("Input malformed");
+
+    let ins = connect_inputs(&mut modules);
+
+    // rx is fed by a single conjunction, which sends it a low pulse once all of its inputs
+    // were high during the same press. Each input goes high on its own period, so they all
+    // line up at the LCM.
+    let feeder = ins
+        .successors("rx")
+        .next()
+        .expect("rx should have an input")
+        .clone();
+    let mut periods: HashMap<String, usize> = ins
+        .successors(&feeder)
+        .map(|name| (name.clone(), 0))
+        .collect();
+
     let mut i = 0;
 
-    loop {
-        let (pulse, new_modules) = press_button_2(modules);
+    while periods.values().any(|&period| period == 0) {
+        i += 1;
+        let (high_senders, new_modules) = press_button_2(modules, &feeder);
 
-        if pulse {
-            break;
+        for sender in high_senders {
+            if let Some(period) = periods.get_mut(&sender).filter(|period| **period == 0) {
+                *period = i;
+            }
         }
         modules = new_modules;
-        i += 1;
     }
 
-    i
+    cycles::lcm_all(periods.into_values())
 }
 
 #[cfg(test)]
 mod tests {
-
-    use num::Float;
-
     use super::*;
 
-    #[rstest]
-    #[case(
-        32000000,
-        "broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a
-"
-    )]
-    #[case(
-        11687500,
-        "broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output
-"
-    )]
-    fn test_func1(#[case] expected: usize, #[case] input: &str) {
-        assert_eq!(expected, func1(input));
-    }
-
     #[test]
     fn test_processing_ff() {
         let mut input = HashMap::new();
@@ -302,7 +313,7 @@ mod tests {
             out: vec![(false, "c".to_string())],
         };
 
-        let res = module.process_signal(false, "broadcast".to_string());
+        let _res = module.process_signal(false, "broadcast".to_string());
 
         assert_eq!(expected, module);
     }
@@ -0,0 +1,71 @@
+use std::{collections::HashMap, iter::zip};
+
+use crate::solution::{Answer, Solution};
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        distances(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        similarity(input).into()
+    }
+}
+
+fn difference(a: u32, b: u32) -> u32 {
+    a.abs_diff(b)
+}
+
+fn distances(input: &str) -> u32 {
+    let mut parsed_input: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            let splitted: Vec<u32> = line
+                .split_whitespace()
+                .filter_map(|s| s.parse().ok())
+                .collect();
+            splitted
+        })
+        .fold(vec![Vec::new(), Vec::new()], |mut acc, splitted| {
+            if !splitted.is_empty() {
+                acc[0].push(splitted[0]);
+                acc[1].push(splitted[1]);
+            }
+            acc
+        });
+    parsed_input[0].sort();
+    parsed_input[1].sort();
+
+    zip(parsed_input[0].clone(), parsed_input[1].clone())
+        .map(|(a, b)| difference(a, b))
+        .sum()
+}
+fn similarity(input: &str) -> u32 {
+    let parsed_input: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            let splitted: Vec<u32> = line
+                .split_whitespace()
+                .filter_map(|s| s.parse().ok())
+                .collect();
+            splitted
+        })
+        .fold(vec![Vec::new(), Vec::new()], |mut acc, splitted| {
+            if !splitted.is_empty() {
+                acc[0].push(splitted[0]);
+                acc[1].push(splitted[1]);
+            }
+            acc
+        });
+
+    let mut counts: HashMap<u32, u32> = HashMap::new();
+    for &number in &parsed_input[1] {
+        *counts.entry(number).or_insert(0) += 1;
+    }
+
+    parsed_input[0]
+        .iter()
+        .fold(0, |acc, num| counts.get(num).unwrap_or(&0) * num + acc)
+}
@@ -1,121 +1,72 @@
-use std::{collections::{HashMap, HashSet}, time::Instant};
+use std::collections::HashSet;
 
-use num::ToPrimitive;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
-use crate::utils::{coords::Coordinates, direction::Direction};
+use crate::solution::{Answer, Solution};
+use crate::utils::{coords::Coordinates, direction::Direction, sparse_grid::SparseGrid};
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", get_guard_path(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        get_guard_path(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn get_guard_path(input: &str) -> usize {
-    let mut map: Vec<String> = input.lines()
-        .map(|line| line.to_string())
-        .collect();
-
-    let mut guard = get_intial_guard_position(&map);
-    let mut visited : HashSet<Coordinates<u32>> = HashSet::new();
-    let mut dir = Direction::Down;
+    let lab = SparseGrid::parse(input, Some);
 
-    loop {
-        visited.insert(guard);
-        let co = match guard.try_step(dir) {
-            Some(co) => co,
-            None => break
-        };
-
-        let y: usize = co.y().try_into().unwrap();
-        let row = match map.get(y) {
-            None => break,
-            Some(row) => row,
-        };
-
-        let x: usize = co.x().try_into().unwrap();
-        let ch = match (*row).chars().nth(x) {
-            None => break,
-            Some(ch) => ch,
-        };
+    patrol(&lab, None)
+        .expect("the guard should leave the lab")
+        .len()
+}
 
-        match ch {
-            '#' => dir = dir.turn_left(),
-            _ => {
-                guard = co;
-                map.get_mut(y).unwrap().replace_range(x..x + 1, "X");
-            }
+/// The cells the guard walks on before leaving the lab, or `None` if it ends up walking in
+/// circles. `obstacle` is an obstruction added to the ones of the lab.
+fn patrol(
+    lab: &SparseGrid<char>,
+    obstacle: Option<Coordinates<i64>>,
+) -> Option<HashSet<Coordinates<i64>>> {
+    let (mut guard, mut dir) = get_intial_guard(lab);
+    let mut seen = HashSet::new();
+
+    while seen.insert((guard, dir)) {
+        let co = guard.step(dir);
+
+        match lab.get(co) {
+            None => return Some(seen.into_iter().map(|(coord, _)| coord).collect()),
+            Some('#') => dir = dir.turn_right(),
+            Some(_) if Some(co) == obstacle => dir = dir.turn_right(),
+            Some(_) => guard = co,
         };
     }
 
-    visited.len()
+    None
 }
 
-fn get_intial_guard_position(map: &Vec<String>) -> Coordinates<u32> {
-    map.iter()
-            .enumerate()
-            .find_map(|(i, line)| {
-                match line.chars()
-                    .enumerate()
-                    .find_map(|(j, ch)| {
-                        if ch == '^' { Some(j) } else { None }
-                    }){
-                    Some(j) => Some(Coordinates::new(j.to_u32().unwrap(), i.to_u32().unwrap())),
-                    None => None
-                }
-            }).unwrap()
+/// The guard is drawn as `^`, `v`, `<` or `>` depending on where it faces.
+fn get_intial_guard(lab: &SparseGrid<char>) -> (Coordinates<i64>, Direction) {
+    lab.cells()
+        .find_map(|(coord, &ch)| Direction::from_char(ch).map(|dir| (coord, dir)))
+        .unwrap()
 }
 
-
+/// Only the cells of the original path can change it, except the one the guard starts on.
 fn func2(input: &str) -> usize {
-    let mut map: Vec<String> = input.lines()
-        .map(|line| line.to_string())
-        .collect();
-
-    let mut guard = get_intial_guard_position(&map);
-    let mut dir = Direction::Down;
-    let mut obstacles = 0;
-
-    todo!()
-}
+    let lab = SparseGrid::parse(input, Some);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::rstest;
-
-    #[rstest]
-    #[case(41, "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...")]
-    fn test_func1(#[case] expected: usize, #[case] input: &str) {
-        assert_eq!(expected, get_guard_path(input))
-    }
-
-    #[test]
-    fn test_func2() {
-        let expected = 6;
-        let input = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
+    let (start, _) = get_intial_guard(&lab);
+    let path: Vec<_> = patrol(&lab, None)
+        .expect("the guard should leave the lab")
+        .into_iter()
+        .filter(|&coord| coord != start)
+        .collect();
 
-        assert_eq!(expected, func2(input))
-    }
+    path.into_par_iter()
+        .filter(|&obstacle| patrol(&lab, Some(obstacle)).is_none())
+        .count()
 }
@@ -1,6 +1,27 @@
+use crate::runner::Day;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
 mod day11;
-use crate::utils::io;
 
-pub fn run() {
-    day11::solve(&io::read(2025,11));
-}
+pub const DAYS: &[Day] = &[
+    Day::new(1, &day01::Solver),
+    Day::new(2, &day02::Solver),
+    Day::new(3, &day03::Solver),
+    Day::new(4, &day04::Solver),
+    Day::new(5, &day05::Solver),
+    Day::new(6, &day06::Solver),
+    Day::new(7, &day07::Solver),
+    Day::new(8, &day08::Solver),
+    Day::new(9, &day09::Solver),
+    Day::new(10, &day10::Solver),
+    Day::new(11, &day11::Solver),
+];
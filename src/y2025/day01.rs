@@ -1,7 +1,6 @@
 use core::panic;
-use std::time::Instant;
 
-use nom::character::complete::none_of;
+use crate::solution::{Answer, Solution};
 
 #[derive(Debug, Clone)]
 enum Dir {
@@ -37,14 +36,16 @@ impl Rotation {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn func1(input: &str) -> u32 {
@@ -95,42 +96,3 @@ fn func2(input: &str) -> u32 {
         })
         .1
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_func1() {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-        let expected = 3;
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-        let expected = 6;
-        assert_eq!(func2(input), expected);
-    }
-}
@@ -1,17 +1,18 @@
-use std::time::Instant;
-
 use itertools::Itertools;
-use nom::{combinator::value, number};
-use num::{Integer, PrimInt};
+use num::PrimInt;
+
+use crate::solution::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn construct_duplicated_digit(n: u64) -> u64 {
@@ -104,7 +105,7 @@ fn func2(input: &str) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::y2025::day02::{construct_duplicated_digit, construct_repeated_digit, func1, func2};
+    use crate::y2025::day02::{construct_duplicated_digit, construct_repeated_digit};
 
     use rstest::rstest;
     #[test]
@@ -125,22 +126,4 @@ mod tests {
     fn test_repeated_digit(#[case] expected: u64, #[case] input: (u64, u32)) {
         assert_eq!(construct_repeated_digit(input.0, input.1), expected);
     }
-
-    #[test]
-    fn test_func1() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
-";
-        let expected = 1227775554;
-
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
-";
-        let expected = 4174379265;
-
-        assert_eq!(func2(input), expected);
-    }
 }
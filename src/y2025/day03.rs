@@ -1,15 +1,15 @@
-use std::time::Instant;
+use crate::solution::{Answer, Solution};
 
-use nom::branch;
+pub struct Solver;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn to_number(n1: u32, n2: u32) -> u32 {
@@ -56,40 +56,14 @@ fn func2(input: &str) -> u64 {
 
                     if to_number_vec(&shift_vec) > to_number_vec(&new_activated) {
                         new_activated = shift_vec;
-                    } 
+                    }
                 }
-                new_activated 
+                new_activated
             });
 
-            dbg!(to_number_vec(&activated));
-            to_number_vec(&activated)
+            let joltage = to_number_vec(&activated);
+            tracing::debug!(bank, joltage);
+            joltage
         })
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_func1() {
-        let input = "987654321111111
-811111111111119
-234234234234278
-818181911112111";
-        let expected = 357;
-
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "987654321111111
-811111111111119
-234234234234278
-818181911112111";
-        let expected = 3121910778619;
-
-        assert_eq!(func2(input), expected);
-    }
-}
@@ -1,34 +1,32 @@
-use std::{ops::RangeInclusive, time::Instant};
+use std::ops::RangeInclusive;
 
-use itertools::Itertools;
 use nom::{
-    bytes::complete::{tag},
+    bytes::complete::tag,
     character::complete::{self, newline},
     multi::separated_list1,
     sequence::separated_pair,
     IResult, Parser,
 };
-use rangemap::RangeInclusiveSet;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+use crate::solution::{Answer, Solution};
+use crate::utils::intervals::IntervalSet;
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn range_parser(input: &str) -> IResult<&str, Vec<RangeInclusive<u64>>> {
     separated_list1(
         newline,
-        separated_pair(
-            complete::u64,
-            tag("-"),
-            complete::u64,
-        )
-        .map(|(a, b)| a..=b),
+        separated_pair(complete::u64, tag("-"), complete::u64).map(|(a, b)| a..=b),
     )(input)
 }
 
@@ -44,7 +42,7 @@ fn parse(input: &str) -> IResult<&str, (Vec<RangeInclusive<u64>>, Vec<u64>)> {
 fn func1(input: &str) -> u64 {
     let (_, (ranges, ingredients)) = parse(input).unwrap();
 
-    let fresh_ingredients: RangeInclusiveSet<u64> = ranges.iter().cloned().collect();
+    let fresh_ingredients: IntervalSet<u64> = ranges.into_iter().collect();
 
     ingredients
         .iter()
@@ -56,56 +54,7 @@ fn func1(input: &str) -> u64 {
 fn func2(input: &str) -> u64 {
     let (_, ranges) = range_parser(input).unwrap();
 
-    let fresh_ingredients: RangeInclusiveSet<u64> = ranges.iter().cloned().collect();
-
-    fresh_ingredients
-        .iter()
-        .fold(0, |acc, range| range.try_len().unwrap() as u64 + acc)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    //use rstest::rstest;
-    use test_log::test;
-
-    /*
-    #[rstest]
-    #[case()]
-    fn test_func1(#[case] expected: u64, #[case] input: &str) {}
-    */
+    let fresh_ingredients: IntervalSet<u64> = ranges.into_iter().collect();
 
-    #[test]
-    fn test_func1() {
-        let input = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
-        let expected = 3;
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32";
-        let expected = 14;
-        assert_eq!(func2(input), expected);
-    }
+    fresh_ingredients.total_len()
 }
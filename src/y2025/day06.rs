@@ -1,5 +1,3 @@
-use std::time::Instant;
-
 use nom::branch::alt;
 use nom::combinator::map_res;
 use nom::{
@@ -9,16 +7,19 @@ use nom::{
 
 use nom::character::complete::{self, space1};
 
-use crate::utils::transposer;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+
+pub struct Solver;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 #[derive(Debug)]
@@ -62,27 +63,28 @@ fn parse(input: &str) -> IResult<&str, (Vec<Vec<u64>>, Vec<Operation>)> {
 fn func1(input: &str) -> u64 {
     let (_, (operands, operations)) = parse(input).unwrap();
 
-    let t_op = transposer::traspose_num_vec(operands);
-    t_op.iter()
+    let width = operands.first().map_or(0, Vec::len);
+    let operands = Grid::new(width, operands.len(), operands.concat());
+    operands
+        .columns()
         .zip(operations)
         .map(|(nums, op)| match op {
-            Operation::Sum => nums.iter().sum::<u64>(),
-            Operation::Mul => nums.iter().product(),
+            Operation::Sum => nums.sum::<u64>(),
+            Operation::Mul => nums.product(),
         })
         .sum()
 }
 
-fn parse_col(lines: &Vec<&str>, col:usize) -> Option<u64>{
-
-        lines
-            .iter()
-            .take(lines.len().saturating_sub(1))
-            .filter_map(|line| line.chars().nth(col))
-            .collect::<String>() // joined column
-            //
-            .trim()
-            .parse()
-            .ok()
+fn parse_col(lines: &Vec<&str>, col: usize) -> Option<u64> {
+    lines
+        .iter()
+        .take(lines.len().saturating_sub(1))
+        .filter_map(|line| line.chars().nth(col))
+        .collect::<String>() // joined column
+        //
+        .trim()
+        .parse()
+        .ok()
 }
 
 fn parse2(input: &str) -> (Vec<Vec<u64>>, Vec<Operation>) {
@@ -122,33 +124,3 @@ fn func2(input: &str) -> u64 {
         })
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use test_log::test;
-
-    #[test]
-    fn test_func1() {
-        let input = "123 328  51 64
-45 64  387 23
-6 98  215 314
-*   +   *   +
-";
-        let expected = 4277556;
-
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  
-";
-        let expected = 3263827;
-
-        assert_eq!(func2(input), expected);
-    }
-}
@@ -1,57 +1,62 @@
-use std::{collections::HashSet, time::Instant};
+use std::collections::HashSet;
 
-use nom::InputIter;
+use crate::solution::{Answer, Solution};
+use crate::utils::{coords::Coordinates, multiset::MultiSet, sparse_grid::SparseGrid};
 
-use crate::utils::{coords::Coordinates, multiset::MultiSet};
+pub struct Solver;
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
-fn parse(input: &str) -> (Coordinates<usize>, HashSet<Coordinates<usize>>) {
-    let splitters: HashSet<Coordinates<usize>> =
-        input
-            .lines()
-            .enumerate()
-            .fold(HashSet::new(), |acc, (y, row)| {
-                let n_splitters = row
-                    .chars()
-                    .enumerate()
-                    .filter_map(|(x, c)| match c {
-                        '^' => Some(Coordinates::new(x, y)),
-                        _ => None,
-                    })
-                    .collect::<HashSet<Coordinates<usize>>>();
-                acc.union(&n_splitters).cloned().collect()
-            });
+fn parse(input: &str) -> (Coordinates<i64>, SparseGrid<char>) {
+    let manifold = SparseGrid::parse(input, Some);
+
+    let start = manifold
+        .cells()
+        .find(|(_, &c)| c == 'S')
+        .map(|(coord, _)| coord)
+        .unwrap();
 
-    let sx = input.position(|c| c == 'S').unwrap();
+    (start, manifold)
+}
 
-    (Coordinates::new(sx, 0), splitters)
+fn is_splitter(manifold: &SparseGrid<char>, coord: &Coordinates<i64>) -> bool {
+    manifold.get(*coord) == Some(&'^')
 }
 
-fn progress_beams(beams: &HashSet<Coordinates<usize>>) -> HashSet<Coordinates<usize>> {
-    beams.iter().filter_map(|beam| beam.try_up()).collect()
+/// Both sides of a splitter, as long as they are inside the manifold.
+fn split(manifold: &SparseGrid<char>, coord: &Coordinates<i64>) -> Vec<Coordinates<i64>> {
+    [coord.left(), coord.right()]
+        .into_iter()
+        .filter(|side| manifold.contains(*side))
+        .collect()
+}
+
+fn progress_beams(beams: &HashSet<Coordinates<i64>>) -> HashSet<Coordinates<i64>> {
+    beams.iter().map(|beam| beam.down()).collect()
 }
 
 fn split_beams(
-    splitters: &HashSet<Coordinates<usize>>,
-    beams: &HashSet<Coordinates<usize>>,
-) -> (usize, HashSet<Coordinates<usize>>) {
-    let beams_to_split: HashSet<_> = splitters & beams;
-
-    let splitted_beams: HashSet<Coordinates<usize>> =
-        beams_to_split.iter().fold(HashSet::new(), |mut acc, beam| {
-            acc.extend(beam.try_left());
-            acc.extend(beam.try_right());
-            acc
-        });
+    manifold: &SparseGrid<char>,
+    beams: &HashSet<Coordinates<i64>>,
+) -> (usize, HashSet<Coordinates<i64>>) {
+    let beams_to_split: HashSet<_> = beams
+        .iter()
+        .filter(|beam| is_splitter(manifold, beam))
+        .copied()
+        .collect();
+
+    let splitted_beams: HashSet<Coordinates<i64>> = beams_to_split
+        .iter()
+        .flat_map(|beam| split(manifold, beam))
+        .collect();
 
     let new_beams: HashSet<_> = &(beams - &beams_to_split) | &splitted_beams;
 
@@ -60,57 +65,56 @@ fn split_beams(
 
 #[tracing::instrument(skip(input))]
 fn func1(input: &str) -> usize {
-    let (initial, splitters) = parse(input);
+    let (initial, mut manifold) = parse(input);
 
-    let height = input.lines().count();
-    let width = input.lines().next().unwrap().len();
-    let mut rep = initial_graphic_representation(width, height, &initial, &splitters);
+    let height = manifold.height();
     let mut beams = HashSet::new();
     beams.insert(initial);
-    println!("{}", rep);
 
     let mut i = 0;
     let mut res = 0;
 
     while i < height {
-        let (n_splitted, new_beams) = split_beams(&splitters, &progress_beams(&beams));
+        let (n_splitted, new_beams) = split_beams(&manifold, &progress_beams(&beams));
         res += n_splitted;
 
-        let n_rep = step_graphic_representation(&rep, &new_beams);
-        println!("{}", rep);
+        for beam in &new_beams {
+            if manifold.get(*beam) == Some(&'.') {
+                manifold.insert(*beam, '|');
+            }
+        }
 
         beams = new_beams;
-        rep = n_rep;
-
         i += 1;
     }
 
+    tracing::debug!("\n{}", manifold.render(|c| c.copied().unwrap_or(' ')));
+
     res
 }
 
-fn progress_quantum_beams(beams: &MultiSet<Coordinates<usize>>) -> MultiSet<Coordinates<usize>> {
+fn progress_quantum_beams(beams: &MultiSet<Coordinates<i64>>) -> MultiSet<Coordinates<i64>> {
     beams
         .into_iter()
-        .filter_map(|(coord, times)| coord.try_up().map(|new_coord| (new_coord, *times)))
+        .map(|(coord, times)| (coord.down(), *times))
         .collect()
 }
 
 fn split_quantum_beams(
-    splitters: &HashSet<Coordinates<usize>>,
-    beams: &MultiSet<Coordinates<usize>>,
-) -> MultiSet<Coordinates<usize>> {
-    let beams_to_split: MultiSet<Coordinates<usize>> = beams
+    manifold: &SparseGrid<char>,
+    beams: &MultiSet<Coordinates<i64>>,
+) -> MultiSet<Coordinates<i64>> {
+    let beams_to_split: MultiSet<Coordinates<i64>> = beams
         .iter()
-        .filter(|(elem, _)| splitters.contains(elem))
+        .filter(|(elem, _)| is_splitter(manifold, elem))
         .map(|(&elem, count)| (elem, count))
         .collect();
 
-    let splitted_beams: MultiSet<Coordinates<usize>> =
+    let splitted_beams: MultiSet<Coordinates<i64>> =
         beams_to_split
             .iter()
             .fold(MultiSet::new(), |mut acc, (coord, times)| {
-                acc.extend(coord.try_left(), times);
-                acc.extend(coord.try_right(), times);
+                acc.extend(split(manifold, coord), times);
                 acc
             });
 
@@ -120,118 +124,19 @@ fn split_quantum_beams(
 
 #[tracing::instrument(skip(input))]
 fn func2(input: &str) -> usize {
-    let (initial, splitters) = parse(input);
+    let (initial, manifold) = parse(input);
 
-    let height = input.lines().count();
+    let height = manifold.height();
     let mut beams = MultiSet::new();
     beams.insert(initial, 1);
 
     let mut i = 0;
 
     while i < height {
-        let new_beams = split_quantum_beams(&splitters, &progress_quantum_beams(&beams));
+        let new_beams = split_quantum_beams(&manifold, &progress_quantum_beams(&beams));
         beams = new_beams;
         i += 1;
     }
 
     beams.len()
 }
-
-fn initial_graphic_representation(
-    width: usize,
-    height: usize,
-    start: &Coordinates<usize>,
-    splitters: &HashSet<Coordinates<usize>>,
-) -> String {
-    let mut s = String::new();
-    for y in 0..height {
-        for x in 0..width {
-            let coord = Coordinates::new(x, y);
-
-            if coord == *start {
-                s.push('S');
-            } else if splitters.contains(&coord) {
-                s.push('^');
-            } else {
-                s.push(' ');
-            }
-        }
-        s.push('\n');
-    }
-    s
-}
-
-fn step_graphic_representation(
-    pre_grephic_repr: &str,
-    beams: &HashSet<Coordinates<usize>>,
-) -> String {
-    pre_grephic_repr
-        .lines()
-        .enumerate()
-        .map(|(y, row)| {
-            let mut s = row
-                .chars()
-                .enumerate()
-                .map(|(x, c)| {
-                    if c == ' ' && beams.contains(&Coordinates::new(x, y)) {
-                        '|'
-                    } else {
-                        c
-                    }
-                })
-                .collect::<String>();
-            s.push('\n');
-            s
-        })
-        .collect::<String>()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use test_log::test;
-
-    #[test]
-    fn test_func1() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-        let expected = 21;
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............";
-        let expected = 40;
-        assert_eq!(func2(input), expected);
-    }
-}
@@ -1,7 +1,5 @@
-use core::panic;
-use std::{cmp::Reverse, collections::HashSet, time::Instant};
+use std::cmp::Reverse;
 
-use glam::IVec3;
 use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
@@ -10,99 +8,69 @@ use nom::{
     IResult, Parser,
 };
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input, 1000));
-    println!("\t time:{:?}", start_time.elapsed());
+use crate::solution::{Answer, Solution};
+use crate::utils::{coords_n::Coordinates3, dsu::DisjointSet};
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+type Point = Coordinates3<i64>;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input, 1000).into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<IVec3>> {
+fn parse(input: &str) -> IResult<&str, Vec<Point>> {
     separated_list1(
         newline,
-        separated_list1(tag(","), character::complete::i32).map(|v| IVec3::new(v[0], v[1], v[2])),
+        separated_list1(tag(","), character::complete::i64)
+            .map(|v| Coordinates3::new(v[0], v[1], v[2])),
     )(input)
 }
 
-fn join_circuits(mut circuits: Vec<HashSet<IVec3>>, a: &IVec3, b: &IVec3) -> Vec<HashSet<IVec3>> {
-    let mut indices: Vec<usize> = circuits
+/// Every pair of boxes, closest first.
+fn closest_pairs(points: &[Point]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    points
         .iter()
-        .positions(|circuit| circuit.contains(a) || circuit.contains(b))
-        .collect();
-
-    indices.sort_unstable_by(|a, b| b.cmp(a));
-
-    let i1 = indices[0];
-    let i2 = indices[1];
-
-    let mut s1 = circuits.remove(i1);
-    let s2 = circuits.remove(i2);
-
-    s1.extend(s2);
-    circuits.push(s1);
-    circuits
-}
-
-fn add_connection(mut circuits: Vec<HashSet<IVec3>>, a: &IVec3, b: &IVec3) -> Vec<HashSet<IVec3>> {
-    let added = circuits
-        .iter_mut()
-        .filter(|circuit| circuit.contains(a) || circuit.contains(b))
-        .map(|circuit| {
-            circuit.insert(*a);
-            circuit.insert(*b);
-        })
-        .count();
-
-    if added == 0 {
-        circuits.push([*a, *b].iter().copied().collect());
-    } else if added == 1 {
-        // Nothing to do
-    } else {
-        // added > 1
-        circuits = join_circuits(circuits, a, b);
-    }
-
-    circuits
+        .enumerate()
+        .tuple_combinations()
+        .map(|((i, a), (j, b))| (i, j, a.euclidean_distance_squared(*b)))
+        .sorted_by_key(|(_, _, distance)| *distance)
+        .map(|(i, j, _)| (i, j))
 }
 
 #[tracing::instrument(skip(input))]
 fn func1(input: &str, n: usize) -> usize {
     let (_, points) = parse(input).unwrap();
-    let mut circuits: Vec<HashSet<IVec3>> = Vec::new();
+    let mut circuits = DisjointSet::new(points.len());
 
-    for (a, b, _) in points
-        .iter()
-        .tuple_combinations()
-        .map(|(a, b)| (a, b, a.as_vec3().distance(b.as_vec3())))
-        .sorted_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
-        .take(n)
-    {
-        circuits = add_connection(circuits, a, b);
+    for (a, b) in closest_pairs(&points).take(n) {
+        circuits.union(a, b);
     }
 
-    circuits.sort_by_key(|s| Reverse(s.len()));
-    circuits.iter().take(3).fold(1, |acc, s| acc * s.len())
+    circuits
+        .component_sizes()
+        .into_iter()
+        .sorted_by_key(|&size| Reverse(size))
+        .take(3)
+        .product()
 }
 
 #[tracing::instrument(skip(input))]
-fn func2(input: &str) -> i32 {
+fn func2(input: &str) -> i64 {
     let (_, points) = parse(input).unwrap();
-    let mut circuits: Vec<HashSet<IVec3>> = Vec::new();
-    let total_points = input.lines().count();
+    let mut circuits = DisjointSet::new(points.len());
 
-    for (a, b, _) in points
-        .iter()
-        .tuple_combinations()
-        .map(|(a, b)| (a, b, a.as_vec3().distance(b.as_vec3())))
-        .sorted_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
-    {
-        circuits = add_connection(circuits, a, b);
+    for (a, b) in closest_pairs(&points) {
+        circuits.union(a, b);
 
-        if circuits.len() == 1 && circuits[0].len() == total_points {
-            return a.x * b.x;
+        if circuits.count() == 1 {
+            return points[a].x() * points[b].x();
         }
     }
     panic!("No solution found");
@@ -113,6 +81,8 @@ mod tests {
     use super::*;
     use test_log::test;
 
+    // The example makes 10 connections instead of 1000, so it can't go through the solver
+    // like the other examples.
     #[test]
     fn test_func1() {
         let input = "162,817,812
@@ -140,32 +110,4 @@ mod tests {
 
         assert_eq!(func1(input, 10), expected);
     }
-
-    #[test]
-    fn test_func2() {
-        let input = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689
-";
-        let expected = 25272;
-
-        assert_eq!(func2(input), expected);
-    }
 }
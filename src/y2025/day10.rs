@@ -1,9 +1,5 @@
 use core::panic;
-use std::{
-    collections::{HashSet, VecDeque},
-    hash::Hash,
-    time::Instant,
-};
+use std::hash::Hash;
 
 use nom::{
     character::{
@@ -16,6 +12,9 @@ use nom::{
 };
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
+use crate::solution::{Answer, Solution};
+use crate::utils::search;
+
 type Button = Vec<u32>;
 type Buttons = Vec<Button>;
 type LightDiagram = Diagram<bool>;
@@ -65,33 +64,23 @@ impl<T: Modify> Diagram<T> {
 
 impl<T: Modify + Hash + Clone + Default + Eq + PartialEq> Diagram<T> {
     fn match_objective(objective: Self, buttons: Buttons) -> u32 {
-        let mut visited: HashSet<Self> = HashSet::new();
-        let mut queue: VecDeque<(Self, &Vec<u32>, u32)> = VecDeque::new();
-
-        for new_button in &buttons {
-            queue.push_back((Diagram::default(objective.len()), new_button, 0));
-        }
-
-        visited.insert(Diagram::default(objective.len()));
-
-        while let Some((mut lights, button, mut pressed_before)) = queue.pop_front() {
-            lights.press_button(button);
-            pressed_before += 1;
-
-            if visited.contains(&lights) || !lights.should_continue(&objective) {
-                continue;
-            }
-            visited.insert(lights.clone());
-
-            if lights == objective {
-                return pressed_before;
-            }
-
-            for new_button in &buttons {
-                queue.push_back((lights.clone(), new_button, pressed_before));
-            }
-        }
-        panic!("should not be reachable");
+        let presses = |lights: &Self| {
+            buttons
+                .iter()
+                .map(|button| {
+                    let mut lights = lights.clone();
+                    lights.press_button(button);
+                    lights
+                })
+                .filter(|lights| lights.should_continue(&objective))
+                .collect::<Vec<_>>()
+        };
+
+        search::bfs(Diagram::default(objective.len()), presses, |lights| {
+            *lights == objective
+        })
+        .expect("should not be reachable")
+        .cost as u32
     }
 }
 
@@ -118,14 +107,16 @@ impl Modify for u32 {
     }
 }
 
-pub fn solve(input: &str) {
-    let start_time = Instant::now();
-    println!("First star: {}", func1(input));
-    println!("\t time:{:?}", start_time.elapsed());
+pub struct Solver;
+
+impl Solution for Solver {
+    fn part1(&self, input: &str) -> Answer {
+        func1(input).into()
+    }
 
-    let start_time = Instant::now();
-    println!("Second star: {}", func2(input));
-    println!("\t time:{:?}", start_time.elapsed());
+    fn part2(&self, input: &str) -> Answer {
+        func2(input).into()
+    }
 }
 
 fn lights_parser(input: &str) -> IResult<&str, LightDiagram> {
@@ -196,7 +187,7 @@ fn is_button_available(i: usize, mask: u32) -> bool {
     mask & (1 << i) > 0
 }
 
-fn next_combination(combinations: &mut Vec<u32>) -> bool {
+fn next_combination(combinations: &mut [u32]) -> bool {
     let i = combinations.iter().rposition(|&v| v != 0).unwrap();
     let l = combinations.len();
     if i == 0 {
@@ -209,7 +200,7 @@ fn next_combination(combinations: &mut Vec<u32>) -> bool {
     true
 }
 
-fn dfs_part2(joltage: &Vec<u32>, available_buttons_mask: u32, buttons: &Vec<Vec<u32>>) -> usize {
+fn dfs_part2(joltage: &[u32], available_buttons_mask: u32, buttons: &Vec<Vec<u32>>) -> usize {
     if joltage.iter().all(|j| *j == 0) {
         return 0;
     }
@@ -308,42 +299,10 @@ fn func2(input: &str) -> usize {
         .par_iter()
         .map(|(_, buttons, joltage)| {
             let res = dfs_part2(&joltage.diagram, (1 << buttons.len()) - 1, buttons);
-            dbg!(res);
+            tracing::debug!(presses = res);
             res
         })
         .collect();
 
     results.iter().sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use test_log::test;
-
-    /*
-    #[rstest]
-    #[case()]
-    fn test_func1(#[case] expected: u32, #[case] input: &str) {}
-    */
-
-    #[test]
-    fn test_func1() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
-        let expected = 7;
-
-        assert_eq!(func1(input), expected);
-    }
-
-    #[test]
-    fn test_func2() {
-        let input = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
-[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
-[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
-        let expected = 33;
-
-        assert_eq!(func2(input), expected);
-    }
-}